@@ -0,0 +1,6420 @@
+// @generated from src/eliza_backend/eliza_backend.did by `cargo xtask codegen`. Do not edit.
+#![allow(dead_code, unused_imports)]
+use candid::{self, CandidType, Deserialize, Principal, Encode, Decode};
+type Result<T> = std::result::Result<T, crate::Error>;
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum LlmProvider {
+  Fallback,
+  Claude,
+  #[serde(rename="OpenAI")]
+  OpenAi,
+  OnChain,
+  Gemini,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct Character {
+  pub bio: Vec<String>,
+  pub model: Option<String>,
+  pub system_prompt: String,
+  pub name: String,
+  pub llm_provider: Option<LlmProvider>,
+  pub style: Vec<String>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SolanaNetworkConfig { pub network_name: String, pub rpc_url: String }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct EvmChainConfig {
+  pub decimals: u8,
+  pub native_symbol: String,
+  pub chain_id: u64,
+  pub rpc_url: String,
+  pub chain_name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SocialPlatform { Mastodon, Reddit, Discord, Farcaster, Twitter }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum Role { Viewer, Operator, Poster, Owner }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct BootstrapConfig {
+  pub auto_reply: Option<bool>,
+  pub character: Option<Character>,
+  pub characters: Option<Vec<(String,Character,)>>,
+  pub solana_networks: Vec<SolanaNetworkConfig>,
+  pub llm_provider: Option<LlmProvider>,
+  pub evm_chains: Vec<EvmChainConfig>,
+  pub admins: Vec<Principal>,
+  pub max_conversation_length: Option<u64>,
+  pub enabled_platforms: Option<Vec<SocialPlatform>>,
+  pub roles: Option<Vec<(Principal,Role,)>>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum AcceptAdminRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum AddAllowedPrincipalRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SocialUserRef { pub platform: SocialPlatform, pub user_id: String }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum AddAllowedSocialUserRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ChainRef { Evm(u64), Icp, Solana(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum AgentEventKind { PostPublished, CyclesTier, Transfer, KnowledgeAdded }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct EventFilter {
+  pub platforms: Vec<SocialPlatform>,
+  pub chains: Vec<ChainRef>,
+  pub kinds: Vec<AgentEventKind>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum AddEventSubscriptionRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum AddSharedMemoryRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum AdoptUnexpectedDepositRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum TxWallet { Evm, Icp, Solana }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TxAnnotation { pub note: Option<String>, pub tags: Vec<String> }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum AnnotateTransactionRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum AnswerHeldMentionRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum AutoPostDraftStatus {
+  Failed(String),
+  Publishing,
+  Rejected,
+  AwaitingApproval,
+  Published(String),
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ApproveAutoPostRet { Ok(AutoPostDraftStatus), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ApproveFaqDraftRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct Account {
+  pub owner: Principal,
+  pub subaccount: Option<serde_bytes::ByteBuf>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ApproveIcrc2Ret { Ok(candid::Nat), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum BanPrincipalRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum BlockSocialUserRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum BootstrapFromRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CancelAdminTransferRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CancelQueuedSendRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CancelScheduledPostRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ChatRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ChatAsRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ChatInRoomRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum BridgeStatus {
+  Failed(String),
+  Refunded{ refund_tx: Option<String> },
+  Done{ receiving_tx: Option<String> },
+  Submitted,
+  Pending,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct BridgeRecord {
+  pub id: u64,
+  pub to_token: String,
+  pub status: BridgeStatus,
+  pub from_amount: String,
+  pub updated_at: u64,
+  pub from_token: String,
+  pub tool: String,
+  pub quoted_to_amount: String,
+  pub recipient: Option<String>,
+  pub attempts: u32,
+  pub created_at: u64,
+  pub events: Vec<String>,
+  pub to_chain_id: u64,
+  pub from_chain_id: u64,
+  pub tx_hash: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CheckBridgeStatusRet { Ok(BridgeRecord), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CyclesTier { Full, QueriesOnly, NoOutcalls, NoAutoPosting }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CheckCyclesTierRet { Ok(CyclesTier), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SimilarPost {
+  pub platform: SocialPlatform,
+  pub posted_at: u64,
+  pub similarity: f64,
+  pub excerpt: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CheckDuplicateContentRet { Ok(Option<SimilarPost>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum OutputDestination {
+  Mastodon,
+  Chat,
+  Reddit,
+  Discord,
+  Farcaster,
+  Twitter,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct DisclosureCheck {
+  pub content: String,
+  pub matched: Vec<String>,
+  pub approval_required_by: Vec<String>,
+  pub financial: bool,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CheckFinancialDisclosureRet { Ok(DisclosureCheck), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CheckIcpBalanceRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum AllowanceDirection { Granted, Received }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct Icrc2Allowance {
+  pub updated_at: u64,
+  pub direction: AllowanceDirection,
+  pub ledger: Principal,
+  pub account: Account,
+  pub allowance: candid::Nat,
+  pub expires_at: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CheckIcrc2AllowanceRet { Ok(Icrc2Allowance), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CheckWorkspaceBalanceRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct MessageSentiment { pub question: bool, pub score: f64 }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ClassifyMessageSentimentRet { Ok(MessageSentiment), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ClearCmsWebhookSecretRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum IdempotencyOutcome {
+  Succeeded{ result_id: String },
+  InFlight,
+  Unknown{ error: String },
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct IdempotencyRecord {
+  pub key: String,
+  pub claimed_at: u64,
+  pub target: String,
+  pub expires_at: Option<u64>,
+  pub outcome: IdempotencyOutcome,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ClearIdempotencyKeyRet { Ok(IdempotencyRecord), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ClearPollRecordingsRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CollectIcrc2Ret { Ok(candid::Nat), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CompactStableStorageRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TwitterOAuth2Status {
+  pub token_proxy: Option<String>,
+  pub scopes: Vec<String>,
+  pub user_id: Option<String>,
+  pub configured: bool,
+  pub client_id: Option<String>,
+  pub last_refresh_error: Option<String>,
+  pub expires_at: Option<u64>,
+  pub pending_authorization: bool,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CompleteTwitterOauth2Ret { Ok(TwitterOAuth2Status), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct AssetDeployment {
+  pub decimals: u8,
+  pub contract: Option<String>,
+  pub chain: ChainRef,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct AssetConfig {
+  pub deployments: Vec<AssetDeployment>,
+  pub symbol: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ConfigureAssetRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct DiscordConfig {
+  pub webhook_url: Option<String>,
+  pub channel_ids: Vec<String>,
+  pub public_key: Option<String>,
+  pub sign_webhooks: Option<bool>,
+  pub bot_token: serde_bytes::ByteBuf,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ConfigureDiscordRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ConfigureEvmChainRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct FarcasterConfig {
+  pub fid: u64,
+  pub channel_id: Option<String>,
+  pub signer_uuid: serde_bytes::ByteBuf,
+  pub api_key: serde_bytes::ByteBuf,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ConfigureFarcasterRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct Icrc1Token {
+  pub fee: candid::Nat,
+  pub decimals: u8,
+  pub ledger: Principal,
+  pub symbol: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ConfigureIcrc1TokenRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct MastodonConfig {
+  pub access_token: serde_bytes::ByteBuf,
+  pub visibility: Option<String>,
+  pub instance_url: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ConfigureMastodonRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct RedditConfig {
+  pub username: String,
+  pub password: serde_bytes::ByteBuf,
+  pub subreddits: Vec<String>,
+  pub client_id: String,
+  pub client_secret: serde_bytes::ByteBuf,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ConfigureRedditRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ConfigureSolanaNetworkRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SupportSystem {
+  Linear{ team_id: String },
+  GitHub{ repository: String },
+  Zendesk{ email: String, subdomain: String },
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SupportIntegrationConfig {
+  pub api_token: serde_bytes::ByteBuf,
+  pub system: SupportSystem,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ConfigureSupportIntegrationRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TwitterCredentials {
+  pub access_token_secret: serde_bytes::ByteBuf,
+  pub api_key: serde_bytes::ByteBuf,
+  pub api_secret: serde_bytes::ByteBuf,
+  pub user_id: Option<String>,
+  pub access_token: serde_bytes::ByteBuf,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ConfigureTwitterRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ConfigureTwitterAccountRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ConfigureWorkspaceDiscordRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ConfigureWorkspaceTwitterRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CreateCharacterRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct NewRestToken {
+  pub id: u64,
+  pub principal: Principal,
+  pub token: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CreateRestTokenRet { Ok(NewRestToken), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CreateRoomRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct WorkspacePolicy {
+  pub max_send_e8s: Option<u64>,
+  pub public_chat: bool,
+  pub paused: bool,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct WorkspaceInfo {
+  pub id: String,
+  pub members: Vec<(Principal,Role,)>,
+  pub character: Character,
+  pub name: String,
+  pub icp_address: String,
+  pub created_at: u64,
+  pub discord_configured: bool,
+  pub twitter_configured: bool,
+  pub policy: WorkspacePolicy,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CreateWorkspaceRet { Ok(WorkspaceInfo), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum DeleteAdminNoteRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum DeleteCharacterRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum DeleteRoomRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum DeleteWorkspaceRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct AgentActionInfo {
+  pub required_role: Option<Role>,
+  pub name: String,
+  pub description: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ToolCapability { pub action: AgentActionInfo, pub available: bool }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PlatformCapability {
+  pub auto_reply: bool,
+  pub rate_limited: bool,
+  pub max_post_chars: u32,
+  pub platform: SocialPlatform,
+  pub calls_per_hour: u32,
+  pub enabled: bool,
+  pub configured: bool,
+  pub auto_posting: bool,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ChainCapability {
+  pub chain: ChainRef,
+  pub name: String,
+  pub native_symbol: String,
+  pub under_maintenance: bool,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct LimitCapabilities {
+  pub max_chat_send_e8s: Option<u64>,
+  pub free_messages_per_day: Option<u32>,
+  pub min_auto_post_interval_seconds: u64,
+  pub max_tool_rounds: u32,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PolicyCapabilities {
+  pub provenance: bool,
+  pub message_billing: bool,
+  pub financial_disclosures: bool,
+  pub on_chain_only_conversations: bool,
+  pub auto_post_approval: bool,
+  pub cycles_tier: CyclesTier,
+  pub moderation: bool,
+  pub paused: bool,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct Capabilities {
+  pub tools: Vec<ToolCapability>,
+  pub llm_provider: LlmProvider,
+  pub platforms: Vec<PlatformCapability>,
+  pub chat_commands: Vec<String>,
+  pub chains: Vec<ChainCapability>,
+  pub caller_role: Option<Role>,
+  pub limits: LimitCapabilities,
+  pub policies: PolicyCapabilities,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct Address { pub chain: Option<ChainRef>, pub address: String }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum TxIntent {
+  IcpTransfer{ to: String, amount_e8s: u64 },
+  SolanaTransfer{ to: String, lamports: u64, network: String },
+  EvmCall{ to: String, data: Option<String>, chain_id: u64, value_wei: String },
+  AssetSend{ to: Address, asset: String, amount: String },
+  SplTransfer{ to: String, mint: String, network: String, amount: u64 },
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TxDescription {
+  pub title: String,
+  pub effects: Vec<String>,
+  pub fields: Vec<(String,String,)>,
+  pub warnings: Vec<String>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum DescribeTransactionRet { Ok(TxDescription), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum DiscardDeadJobRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum DiscardPostDraftRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum DiscardQuarantinedRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum DisconnectTwitterOauth2Ret { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum DismissHeldMentionRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum EditCharacterRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum PostDraftStatus { Open, Scheduled(u64), Discarded }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PostDraft {
+  pub id: u64,
+  pub status: PostDraftStatus,
+  pub topic: String,
+  pub content: String,
+  pub score_notes: Vec<String>,
+  pub batch_id: u64,
+  pub platform: SocialPlatform,
+  pub created_at: u64,
+  pub edited_at: Option<u64>,
+  pub edited_by: Option<Principal>,
+  pub score: u8,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum EditPostDraftRet { Ok(PostDraft), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SendRoute { Bridge{ from_chain_id: u64 }, Direct }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SendPlan {
+  pub to: String,
+  pub asset: String,
+  pub contract: Option<String>,
+  pub chain: ChainRef,
+  pub amount: String,
+  pub estimated_fee: String,
+  pub route: SendRoute,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SendReceipt {
+  pub explorer_url: Option<String>,
+  pub tx_id: String,
+  pub plan: SendPlan,
+  pub bridge_id: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum QueuedSendStatus { Queued, Failed(String), Sent(SendReceipt), Sending }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct QueuedSend {
+  pub id: u64,
+  pub to: Address,
+  pub status: QueuedSendStatus,
+  pub asset: String,
+  pub waiting_on: ChainRef,
+  pub annotation: Option<TxAnnotation>,
+  pub amount: String,
+  pub queued_at: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum EndChainMaintenanceRet { Ok(Vec<QueuedSend>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum PostSeriesStatus { Ended, Paused, Active }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PostSeries {
+  pub id: u64,
+  pub status: PostSeriesStatus,
+  pub cron: Option<String>,
+  pub platform: SocialPlatform,
+  pub created_at: u64,
+  pub end_time: Option<u64>,
+  pub occurrences: u32,
+  pub interval_seconds: u64,
+  pub template: String,
+  pub next_run_at: u64,
+  pub last_post_id: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum EndPostSeriesRet { Ok(PostSeries), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SupportTicket {
+  pub url: Option<String>,
+  pub ticket_id: String,
+  pub created_at: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum EscalateConversationRet { Ok(SupportTicket), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CostOperation {
+  SendDiscordMessage,
+  Chat{ provider: Option<LlmProvider> },
+  SendEvmNative{ chain_id: u64 },
+  UniswapSwap{ chain_id: u64 },
+  PostTweet,
+  SendIcp,
+  LifiBridge{ from_chain_id: u64 },
+  SendSplToken,
+  SendSolana,
+  SendErc20{ chain_id: u64 },
+  JupiterSwap,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ExternalFee { pub description: String, pub amount: String }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct OutcallCost {
+  pub max_response_bytes: u64,
+  pub max_calls: u8,
+  pub cycles_charged: candid::Nat,
+  pub cycles_attached: candid::Nat,
+  pub purpose: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SignatureCost {
+  pub key: String,
+  pub cycles: candid::Nat,
+  pub purpose: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct CostEstimate {
+  pub external_fees: Vec<ExternalFee>,
+  pub peak_cycles_attached: candid::Nat,
+  pub outcalls: Vec<OutcallCost>,
+  pub max_cycles: candid::Nat,
+  pub notes: Vec<String>,
+  pub operation: String,
+  pub signatures: Vec<SignatureCost>,
+  pub expected_cycles: candid::Nat,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum EstimateCostRet { Ok(CostEstimate), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SpendingLimit {
+  pub token: Option<String>,
+  pub daily_cap: Option<candid::Nat>,
+  pub chain: ChainRef,
+  pub max_per_tx: Option<candid::Nat>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ChatCommandPolicy { pub max_send_e8s: Option<u64> }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GovernanceAction {
+  RemoveSpendingLimit{ token: Option<String>, chain: ChainRef },
+  SetSpendingLimit(SpendingLimit),
+  AddAdmin(Principal),
+  SetPrimaryAdmin(Principal),
+  SetLlmProvider(LlmProvider),
+  SetSpendingPolicy(ChatCommandPolicy),
+  SetRole{ principal: Principal, role: Option<Role> },
+  UpdateCharacter{ id: String, character: Character },
+  RemoveAdmin(Principal),
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ExecuteGovernanceActionRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ExecuteJupiterSwapRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ExecuteLifiBridgeRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ExecuteUniswapSwapRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ExportBootstrapConfigRet { Ok(BootstrapConfig), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ExportConversationRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TxFilter {
+  pub tag: Option<String>,
+  pub text: Option<String>,
+  pub untagged: Option<bool>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ExportTransactionsRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GenerateDraftsRet { Ok(Vec<PostDraft>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum AccessMode {
+  Open,
+  Allowlist,
+  TokenGated{ ledger: Principal, min_balance: u64 },
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct AccessControl {
+  pub banned_principals: Vec<Principal>,
+  pub allowed_principals: Vec<Principal>,
+  pub mode: AccessMode,
+  pub allowed_social_users: Vec<SocialUserRef>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetAccessControlRet { Ok(AccessControl), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct AddressBookEntry {
+  pub updated_at: u64,
+  pub verified: bool,
+  pub chain: ChainRef,
+  pub label: String,
+  pub address: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetAddressBookRet { Ok(Vec<AddressBookEntry>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetAdminNotesKeyRet { Ok(serde_bytes::ByteBuf), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetAdminNotesVerificationKeyRet {
+  Ok(serde_bytes::ByteBuf),
+  Err(String),
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum KeyRotationReason { Compromise, Scheduled, Manual }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum WatchedTimer { SocialPolling, AutoPosting }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum AuditAction {
+  AdminNoteKeyIssued{ role: Role },
+  AdminTransferProposed{ to: Principal, expires_at: u64 },
+  AdminTransferAccepted{ from: Principal },
+  RolesBootstrapped{ owners: Vec<Principal>, others: u32 },
+  KeyEpochRetired{ epoch: u32 },
+  KeyEpochStarted{ epoch: u32, reason: KeyRotationReason },
+  RoleChanged{ principal: Principal, role: Option<Role> },
+  AdminTransferCancelled{ to: Principal },
+  AdminNoteChanged{ id: u64, deleted: bool },
+  StateMigrated{ to: u32, from: u32 },
+  TimerStalled{ timer: WatchedTimer, restarted: bool },
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct AuditEntry {
+  pub seq: u64,
+  pub action: AuditAction,
+  pub actor: Principal,
+  pub timestamp: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetAuditLogRet { Ok(Vec<AuditEntry>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SocialBlockKind { Mute, Block }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct AutoBlockConfig {
+  pub hostile_action: SocialBlockKind,
+  pub max_messages_per_hour: u32,
+  pub hostile_messages: u32,
+  pub window_days: u32,
+  pub enabled: bool,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetAutoBlockConfigRet { Ok(AutoBlockConfig), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct AutoPostApprovalConfig {
+  pub enabled: bool,
+  pub approver_ids: Vec<String>,
+  pub discord_channel_id: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PostingWindow { pub end_hour: u8, pub start_hour: u8 }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct AutoPostTopic {
+  pub weight: u32,
+  pub topic: String,
+  pub windows: Vec<PostingWindow>,
+  pub cooldown_secs: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct AutoPostConfig {
+  pub topic_credits: Vec<(String,i64,)>,
+  pub cron: Option<String>,
+  pub topic_last_used: Vec<(String,u64,)>,
+  pub platform: SocialPlatform,
+  pub enabled: bool,
+  pub interval_seconds: u64,
+  pub topics: Vec<AutoPostTopic>,
+  pub blocked_phrases: Vec<String>,
+  pub last_post_time: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct AutoPostDraft {
+  pub id: u64,
+  pub status: AutoPostDraftStatus,
+  pub topic: String,
+  pub content: String,
+  pub preview_message_id: Option<String>,
+  pub platform: SocialPlatform,
+  pub created_at: u64,
+  pub variant: Option<String>,
+  pub decided_at: Option<u64>,
+  pub decided_by: Option<String>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetAutoPostDraftsRet { Ok(Vec<AutoPostDraft>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PostEngagement {
+  pub likes: u64,
+  pub replies: u64,
+  pub reposts: u64,
+  pub quotes: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct VariantStats {
+  pub measured: u64,
+  pub active: bool,
+  pub engagement_per_post: Option<f64>,
+  pub posts: u64,
+  pub variant: String,
+  pub engagement: PostEngagement,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct AutoPostVariantReport {
+  pub best: Option<String>,
+  pub metrics_updated_at: Option<u64>,
+  pub variants: Vec<VariantStats>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetAutoPostVariantStatsRet { Ok(AutoPostVariantReport), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct AutoPostVariant {
+  pub weight: u32,
+  pub name: String,
+  pub instructions: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetAutoPostVariantsRet { Ok(Vec<AutoPostVariant>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct BillingConfig {
+  pub token_symbol: String,
+  pub price_per_message: u64,
+  pub enabled: bool,
+  pub ledger: Principal,
+  pub free_messages_per_day: u32,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct BlockedSocialUser {
+  pub kind: SocialBlockKind,
+  pub user: SocialUserRef,
+  pub created_at: u64,
+  pub expires_at: Option<u64>,
+  pub reason: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetBlockedSocialUsersRet { Ok(Vec<BlockedSocialUser>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct BridgePolicy {
+  pub alert_via_discord: bool,
+  pub max_attempts: u32,
+  pub max_requote_slippage_bps: u32,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetBtcAddressRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PortfolioAsset {
+  pub balance: String,
+  pub chain: String,
+  pub token_address: Option<String>,
+  pub address: String,
+  pub symbol: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct Portfolio {
+  pub icp: PortfolioAsset,
+  pub total_chains: u32,
+  pub solana_assets: Vec<PortfolioAsset>,
+  pub evm_assets: Vec<PortfolioAsset>,
+  pub unexpected_assets: Vec<PortfolioAsset>,
+  pub last_updated: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct CertifiedTransactions {
+  pub ids: Vec<u64>,
+  pub certificate: serde_bytes::ByteBuf,
+  pub witness: serde_bytes::ByteBuf,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetCertifiedTransactionHistoryRet {
+  Ok(CertifiedTransactions),
+  Err(String),
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct CertifiedAddresses {
+  pub certificate: serde_bytes::ByteBuf,
+  pub witness: serde_bytes::ByteBuf,
+  pub addresses: Vec<(String,String,)>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetCertifiedWalletAddressesRet { Ok(CertifiedAddresses), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct FeeModel {
+  pub priority_fee_wei: Option<u64>,
+  pub max_fee_multiplier: Option<u32>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ChainDescriptor {
+  pub fee_model: FeeModel,
+  pub decimals: u8,
+  pub wrapped_native: Option<String>,
+  pub name: String,
+  pub native_symbol: String,
+  pub dex_router: Option<String>,
+  pub dex_quoter: Option<String>,
+  pub chain_id: u64,
+  pub rpc_url: String,
+  pub multicall: Option<String>,
+  pub explorer_tx_url: Option<String>,
+  pub explorer_address_url: Option<String>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct MaintenanceWindow {
+  pub chain: ChainRef,
+  pub since: u64,
+  pub reason: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ChatJobStatus { Queued, Failed(String), Done(String), Running }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ChatJob {
+  pub id: u64,
+  pub status: ChatJobStatus,
+  pub updated_at: u64,
+  pub owner: Principal,
+  pub created_at: u64,
+  pub prompt: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetChatResultRet { Ok(ChatJob), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CkbtcTransactionStatus {
+  Signing,
+  Confirmed(String),
+  Sending(String),
+  Minted,
+  AmountTooLow,
+  Unknown,
+  Submitted(String),
+  Pending,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CkbtcTransactionType { Deposit, Withdrawal }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct CkbtcTransactionRecord {
+  pub id: u64,
+  pub status: CkbtcTransactionStatus,
+  pub block_index: u64,
+  pub btc_txid: Option<String>,
+  pub amount_sats: u64,
+  pub timestamp: u64,
+  pub tx_type: CkbtcTransactionType,
+  pub btc_address: Option<String>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CkethWithdrawalStatus {
+  TxSigned(String),
+  Finalized(String),
+  NotFound,
+  Reimbursed,
+  TxSent(String),
+  TxCreated,
+  Pending,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct CkethWithdrawal {
+  pub id: u64,
+  pub status: CkethWithdrawalStatus,
+  pub block_index: u64,
+  pub recipient: String,
+  pub ledger: Principal,
+  pub timestamp: u64,
+  pub amount: candid::Nat,
+  pub symbol: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct Config {
+  pub llm_provider: LlmProvider,
+  pub max_conversation_length: u64,
+  pub roles: Vec<(Principal,Role,)>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct Message { pub content: String, pub role: String }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct MessagePage {
+  pub total: u64,
+  pub messages: Vec<Message>,
+  pub next_cursor: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetConversationHistoryOfRet { Ok(MessagePage), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ConversationPrivacy {
+  pub pinned_at: Option<u64>,
+  pub model: String,
+  pub on_chain_only: bool,
+  pub provider: LlmProvider,
+  pub required_by_policy: bool,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CyclesSubsystem {
+  Evm,
+  Llm,
+  Signing,
+  Solana,
+  KeyDerivation,
+  Discord,
+  ComputeAndStorage,
+  OtherOutcalls,
+  Twitter,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct CyclesSample {
+  pub at: u64,
+  pub balance: candid::Nat,
+  pub by_subsystem: Vec<(CyclesSubsystem,candid::Nat,)>,
+  pub burned: candid::Nat,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct CyclesAlertConfig {
+  pub min_days_left: u32,
+  pub repeat_hours: u32,
+  pub admin_discord_user_ids: Vec<String>,
+  pub alert_via_discord: bool,
+  pub enabled: bool,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct CyclesStats {
+  pub burn_per_day: candid::Nat,
+  pub balance: candid::Nat,
+  pub last_alert_at: u64,
+  pub by_subsystem: Vec<(CyclesSubsystem,candid::Nat,)>,
+  pub history: Vec<CyclesSample>,
+  pub days_left: Option<u64>,
+  pub config: CyclesAlertConfig,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetCyclesStatsRet { Ok(CyclesStats), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct CyclesTierConfig {
+  pub no_auto_posting_below: candid::Nat,
+  pub queries_only_below: candid::Nat,
+  pub no_outcalls_below: candid::Nat,
+  pub enabled: bool,
+  pub recovery_margin_percent: u8,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct CyclesTierStatus {
+  pub balance: candid::Nat,
+  pub changed_at: u64,
+  pub tier: CyclesTier,
+  pub config: CyclesTierConfig,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct CyclesTopUpConfig {
+  pub auto: bool,
+  pub max_per_day: u32,
+  pub amount_e8s: u64,
+  pub below_days_left: u32,
+  pub below_balance: candid::Nat,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct DiscordChannelProfile {
+  pub channel_id: String,
+  pub auto_reply: bool,
+  pub character_id: Option<String>,
+  pub max_replies_per_hour: Option<u32>,
+  pub style: Option<String>,
+  pub allowed_role_ids: Vec<String>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetDiscordChannelProfilesRet {
+  Ok(Vec<DiscordChannelProfile>),
+  Err(String),
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct DiscordConversation {
+  pub id: String,
+  pub channel_id: String,
+  pub updated_at: u64,
+  pub turns: Vec<Message>,
+  pub message_ids: Vec<String>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetDiscordConversationsRet {
+  Ok(Vec<DiscordConversation>),
+  Err(String),
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct DuplicateGuardConfig {
+  pub window_days: u32,
+  pub enabled: bool,
+  pub max_regenerations: u32,
+  pub similarity_threshold: f64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetDuplicateGuardConfigRet { Ok(DuplicateGuardConfig), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetErc20BalanceRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct EventSubscription {
+  pub id: u64,
+  pub method: String,
+  pub active: bool,
+  pub created_at: u64,
+  pub filter: EventFilter,
+  pub consecutive_failures: u32,
+  pub subscriber: Principal,
+  pub delivered_seq: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum AgentEventData {
+  PostPublished{
+    post_id: Option<u64>,
+    platform: SocialPlatform,
+    result_id: String,
+  },
+  CyclesTierChanged{ to: CyclesTier, balance: candid::Nat, from: CyclesTier },
+  Transfer{
+    to: String,
+    token: Option<String>,
+    tx_id: String,
+    chain: ChainRef,
+    to_label: Option<String>,
+    amount: String,
+  },
+  KnowledgeAdded{ title: String, source: String },
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct AgentEvent {
+  pub seq: u64,
+  pub data: AgentEventData,
+  pub timestamp: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetEventsRet { Ok(Vec<AgentEvent>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetEvmAddressRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetEvmBalanceRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum EvmTransactionStatus {
+  Failed(String),
+  Confirmed(u64),
+  Submitted(String),
+  Pending,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct EvmTransactionRecord {
+  pub id: u64,
+  pub to: String,
+  pub status: EvmTransactionStatus,
+  pub data: Option<String>,
+  pub chain_id: u64,
+  pub value_wei: String,
+  pub annotation: Option<TxAnnotation>,
+  pub timestamp: u64,
+  pub tx_hash: Option<String>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct EvmWalletInfo {
+  pub chain_id: u64,
+  pub address: String,
+  pub chain_name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetEvmWalletInfoRet { Ok(EvmWalletInfo), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ExplorerTemplate { pub chain: ChainRef, pub tx_url: String }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum FaqDraftStatus { Approved, Rejected, Pending }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct FaqDraft {
+  pub id: u64,
+  pub status: FaqDraftStatus,
+  pub question: String,
+  pub cluster_key: String,
+  pub created_at: u64,
+  pub answer: String,
+  pub asked_count: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetFaqDraftsRet { Ok(Vec<FaqDraft>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum DisclosureAction { RequireApproval, AppendDisclaimer }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct JurisdictionRule {
+  pub action: DisclosureAction,
+  pub active: bool,
+  pub jurisdiction: String,
+  pub disclaimer: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct FinancialDisclosureConfig {
+  pub extra_keywords: Vec<String>,
+  pub enabled: bool,
+  pub rules: Vec<JurisdictionRule>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetFinancialDisclosureConfigRet {
+  Ok(FinancialDisclosureConfig),
+  Err(String),
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct IncomingMessage {
+  pub id: String,
+  pub content: String,
+  pub author_name: String,
+  pub author_followers: Option<u64>,
+  pub source: Option<String>,
+  pub conversation_id: Option<String>,
+  pub platform: SocialPlatform,
+  pub account: Option<String>,
+  pub timestamp: u64,
+  pub replied: bool,
+  pub author_id: String,
+  pub processed: bool,
+  pub in_reply_to: Option<String>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct HeldMention {
+  pub id: u64,
+  pub sentiment: MessageSentiment,
+  pub message: IncomingMessage,
+  pub held_at: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetHeldMentionsRet { Ok(Vec<HeldMention>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetIcrc1BalanceRet { Ok(candid::Nat), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetIcrc2AllowancesRet { Ok(Vec<Icrc2Allowance>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct JupiterQuote {
+  pub price_impact_pct: String,
+  pub in_amount: String,
+  pub slippage_bps: u64,
+  pub output_mint: String,
+  pub input_mint: String,
+  pub out_amount: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetJupiterQuoteRet { Ok(JupiterQuote), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct KeyRotation {
+  pub last_error: Option<String>,
+  pub total: u32,
+  pub resealed: u32,
+  pub to_epoch: u32,
+  pub from_epoch: u32,
+  pub started_at: u64,
+  pub reason: KeyRotationReason,
+  pub finished_at: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct KeyRotationConfig { pub interval_days: Option<u32> }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct KeyRotationStatus {
+  pub active: Option<KeyRotation>,
+  pub current_epoch: u32,
+  pub history: Vec<KeyRotation>,
+  pub epoch_started_at: u64,
+  pub retired_epochs: Vec<u32>,
+  pub config: KeyRotationConfig,
+  pub stale_secrets: u32,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetKeyRotationStatusRet { Ok(KeyRotationStatus), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct LiFiBridgeQuote {
+  pub to_token: String,
+  pub from_amount: String,
+  pub from_token: String,
+  pub tool: String,
+  pub to_chain_id: u64,
+  pub from_chain_id: u64,
+  pub to_amount: String,
+  pub estimated_gas: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetLifiQuoteRet { Ok(LiFiBridgeQuote), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct MemoryConfig {
+  pub top_k: u32,
+  pub enabled: bool,
+  pub min_score: f32,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ModerationConfig {
+  pub blocked_patterns: Vec<String>,
+  pub enabled: bool,
+  pub blocked_keywords: Vec<String>,
+  pub use_openai: bool,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetModerationConfigRet { Ok(ModerationConfig), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct MyBilling {
+  pub credits: u32,
+  pub billing_enabled: bool,
+  pub token_symbol: Option<String>,
+  pub price_per_message: u64,
+  pub ledger: Option<Principal>,
+  pub free_messages_left_today: u32,
+  pub spender: Principal,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct UsageTotals {
+  pub completion_tokens: u64,
+  pub estimated_requests: u64,
+  pub requests: u64,
+  pub prompt_tokens: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PrincipalUsage {
+  pub owner: Option<Principal>,
+  pub totals: UsageTotals,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct DailyUsage {
+  pub day: u64,
+  pub provider: LlmProvider,
+  pub totals: UsageTotals,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct UsageStats {
+  pub since_day: u64,
+  pub total: UsageTotals,
+  pub by_principal: Vec<PrincipalUsage>,
+  pub by_provider: Vec<DailyUsage>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TopicStatus {
+  pub weight: u32,
+  pub cooldown_until: Option<u64>,
+  pub topic: String,
+  pub credit: i64,
+  pub eligible: bool,
+  pub last_used: Option<u64>,
+  pub in_window: bool,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TopicPreview {
+  pub at: u64,
+  pub next_topic: Option<String>,
+  pub topics: Vec<TopicStatus>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetNextAutoPostTopicRet { Ok(TopicPreview), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum NotificationDelivery { Off, DailyDigest, Immediate }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct NotificationPreferences {
+  pub reply_mentions: NotificationDelivery,
+  pub discord_user_id: String,
+  pub campaign_updates: NotificationDelivery,
+  pub reminders: NotificationDelivery,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct NotificationStatus {
+  pub pending_digest_items: u64,
+  pub last_digest_at: u64,
+  pub registered_users: u64,
+  pub pending_immediate: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetNotificationStatusRet { Ok(NotificationStatus), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct HostHealth {
+  pub failures: u64,
+  pub host: String,
+  pub requests: u64,
+  pub open_until: Option<u64>,
+  pub consecutive_failures: u32,
+  pub last_failure: Option<String>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetOutcallHealthRet { Ok(Vec<HostHealth>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct OutcallPolicy {
+  pub breaker_threshold: u32,
+  pub max_get_attempts: u8,
+  pub retry_budget_cycles: Option<candid::Nat>,
+  pub allowed_hosts: Option<Vec<String>>,
+  pub breaker_cooldown_seconds: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum PostProcessor {
+  StripMarkdown,
+  LinkTickers{ url_template: String },
+  AppendDisclosure{ tag: String },
+  BannedPhrases{ reject: bool, phrases: Vec<String> },
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetOutputPipelinesRet {
+  Ok(Vec<(OutputDestination,Vec<PostProcessor>,)>),
+  Err(String),
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PauseInfo {
+  pub paused_at: u64,
+  pub paused_by: Principal,
+  pub reason: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PaymentRequest {
+  pub uri: String,
+  pub qr_svg: String,
+  pub asset: String,
+  pub http_path: String,
+  pub chain: ChainRef,
+  pub address: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetPaymentRequestRet { Ok(Vec<PaymentRequest>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PendingAdminTransfer {
+  pub to: Principal,
+  pub from: Principal,
+  pub expires_at: u64,
+  pub proposed_at: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetPendingChatSendsRet {
+  Ok(Vec<(Principal,TxDescription,)>),
+  Err(String),
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PromptTemplate { pub name: String, pub template: String }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct KnowledgeDoc { pub title: String, pub content: String }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PersonaAssets {
+  pub prompt_templates: Vec<PromptTemplate>,
+  pub knowledge_docs: Vec<KnowledgeDoc>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PersonaProvenance {
+  pub imported_at: u64,
+  pub publisher: Principal,
+  pub published_at: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PollRecorderConfig { pub enabled: bool, pub capacity: u32 }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetPollRecorderConfigRet { Ok(PollRecorderConfig), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetPollRecordingRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetPortfolioRet { Ok(Portfolio), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PostQueueDepth {
+  pub scheduled: u32,
+  pub alerts: u32,
+  pub replies: u32,
+  pub auto_posts: u32,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PostQueueStatus {
+  pub due: PostQueueDepth,
+  pub upcoming: u32,
+  pub rate_limited: bool,
+  pub platform: SocialPlatform,
+  pub oldest_due_wait_seconds: Option<u64>,
+  pub processing: u32,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PrivacyPolicy { pub on_chain_only: bool }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ProvenanceRecord {
+  pub id: u64,
+  pub content: String,
+  pub workspace_id: Option<String>,
+  pub content_hash: String,
+  pub canister_id: Principal,
+  pub published_at: Option<u64>,
+  pub platform: SocialPlatform,
+  pub result_id: Option<String>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ProvenanceConfig {
+  pub base_url: Option<String>,
+  pub enabled: bool,
+  pub platforms: Vec<SocialPlatform>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PersonaSettings {
+  pub max_conversation_length: Option<u64>,
+  pub auto_post_topics: Vec<String>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PersonaBundle {
+  pub format_version: u32,
+  pub character: Character,
+  pub assets: PersonaAssets,
+  pub settings: PersonaSettings,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SignedPersonaBundle {
+  pub signature: serde_bytes::ByteBuf,
+  pub publisher: Principal,
+  pub published_at: u64,
+  pub bundle: PersonaBundle,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum QuarantineStatus { Held, Released, Discarded }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct DiscordEmbedField {
+  pub value: String,
+  pub name: String,
+  pub inline: bool,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct DiscordEmbed {
+  pub url: Option<String>,
+  pub title: Option<String>,
+  pub color: Option<u32>,
+  pub description: Option<String>,
+  pub fields: Vec<DiscordEmbedField>,
+  pub footer: Option<String>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct DiscordButton { pub url: String, pub label: String }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct DiscordMessagePayload {
+  pub embeds: Vec<DiscordEmbed>,
+  pub buttons: Vec<DiscordButton>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PostMetadata {
+  pub media_urls: Option<Vec<String>>,
+  pub thread_ids: Option<Vec<String>>,
+  pub reply_to_id: Option<String>,
+  pub result_id: Option<String>,
+  pub thread: Option<Vec<String>>,
+  pub media_ids: Option<Vec<String>>,
+  pub subreddit: Option<String>,
+  pub discord_payload: Option<DiscordMessagePayload>,
+  pub variant: Option<String>,
+  pub discord_channel_id: Option<String>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ModerationSource {
+  AutoPost{ topic: String, variant: Option<String> },
+  AutoReply{ metadata: Option<PostMetadata>, message_id: String },
+  AgentPost{ scheduled_time: u64 },
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct QuarantinedOutput {
+  pub id: u64,
+  pub status: QuarantineStatus,
+  pub content: String,
+  pub source: ModerationSource,
+  pub platform: SocialPlatform,
+  pub created_at: u64,
+  pub reason: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetQuarantineRet { Ok(Vec<QuarantinedOutput>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetQueuedSendsRet { Ok(Vec<QueuedSend>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ReplyRuleAction { Reply, Ignore }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ReplyRuleCondition {
+  Any,
+  Keywords(Vec<String>),
+  Authors(Vec<String>),
+  MinFollowers(u64),
+  Pattern(String),
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ReplyRule {
+  pub action: ReplyRuleAction,
+  pub name: String,
+  pub platforms: Vec<SocialPlatform>,
+  pub author_cooldown_secs: Option<u64>,
+  pub conditions: Vec<ReplyRuleCondition>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetReplyRulesRet { Ok(Vec<ReplyRule>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct MessagePayment {
+  pub block_index: candid::Nat,
+  pub ledger: Principal,
+  pub timestamp: u64,
+  pub payer: Principal,
+  pub amount: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct RevenueTotals {
+  pub credited_messages: u64,
+  pub free_messages: u64,
+  pub amount: candid::Nat,
+  pub paid_messages: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct DailyRevenue {
+  pub day: u64,
+  pub totals: RevenueTotals,
+  pub ledger: Principal,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PayerRevenue {
+  pub totals: RevenueTotals,
+  pub ledger: Principal,
+  pub payer: Principal,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct RevenueReport {
+  pub recent_payments: Vec<MessagePayment>,
+  pub since_day: u64,
+  pub by_day: Vec<DailyRevenue>,
+  pub outstanding_credits: u64,
+  pub top_payers: Vec<PayerRevenue>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetRevenueReportRet { Ok(RevenueReport), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetRolesRet { Ok(Vec<(Principal,Role,)>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RolloutStatus { Active, Promoted, RolledBack }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ArmMetrics {
+  pub messages: u64,
+  pub ratings: u64,
+  pub average_rating: Option<f64>,
+  pub conversations: u64,
+  pub messages_per_conversation: f64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct RolloutReport {
+  pub id: u64,
+  pub status: RolloutStatus,
+  pub control: ArmMetrics,
+  pub character_id: String,
+  pub candidate_name: String,
+  pub percent: u8,
+  pub candidate: ArmMetrics,
+  pub started_at: u64,
+  pub finished_at: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetRolloutReportsRet { Ok(Vec<RolloutReport>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetRoomHistoryRet { Ok(Vec<Message>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum PostStatus { Failed(String), Processing, Completed, Pending }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum PostPriority { AutoPost, Reply, Scheduled, Alert }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ScheduledPost {
+  pub id: u64,
+  pub scheduled_time: u64,
+  pub status: PostStatus,
+  pub content: String,
+  pub retry_count: u32,
+  pub metadata: Option<PostMetadata>,
+  pub platform: SocialPlatform,
+  pub created_at: u64,
+  pub account: Option<String>,
+  pub priority: Option<PostPriority>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RotatableSecret {
+  TwitterOAuth2Tokens,
+  MastodonAccessToken,
+  TwitterCredentials,
+  OpenAiApiKey,
+  FarcasterCredentials,
+  RedditCredentials,
+  DiscordBotToken,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SecretMetadata {
+  pub secret: RotatableSecret,
+  pub created_at: u64,
+  pub version: u32,
+  pub verified_at: Option<u64>,
+  pub last_used: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetSecretMetadataRet { Ok(Vec<SecretMetadata>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SecretsStatus {
+  pub sealed: Vec<String>,
+  pub unsealed: Vec<String>,
+  pub key_loaded: bool,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetSecretsStatusRet { Ok(SecretsStatus), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SentimentClassifier { Llm, Lexicon }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SentimentGateConfig {
+  pub classifier: SentimentClassifier,
+  pub hostile_threshold: f64,
+  pub enabled: bool,
+  pub reply_threshold: f64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetSentimentGateConfigRet { Ok(SentimentGateConfig), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct DiscordChannelInfo {
+  pub can_read_history: bool,
+  pub channel_id: String,
+  pub name: Option<String>,
+  pub guild_id: Option<String>,
+  pub error: Option<String>,
+  pub can_view: bool,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TwitterAccountInfo {
+  pub username: String,
+  pub name: String,
+  pub user_id: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PlatformMetadata {
+  pub discord_channels: Vec<DiscordChannelInfo>,
+  pub discord_error: Option<String>,
+  pub twitter: Option<TwitterAccountInfo>,
+  pub discord_bot_username: Option<String>,
+  pub twitter_error: Option<String>,
+  pub discord_bot_id: Option<String>,
+  pub refreshed_at: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SocialStatus {
+  pub last_mastodon_poll: u64,
+  pub platform_metadata: Option<PlatformMetadata>,
+  pub last_farcaster_poll: u64,
+  pub farcaster_configured: bool,
+  pub last_reddit_poll: u64,
+  pub unprocessed_messages: u32,
+  pub discord_configured: bool,
+  pub last_discord_poll: u64,
+  pub pending_posts: u32,
+  pub polling_active: bool,
+  pub mastodon_configured: bool,
+  pub last_twitter_poll: u64,
+  pub twitter_configured: bool,
+  pub enabled_platforms: Vec<SocialPlatform>,
+  pub reddit_configured: bool,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetSolanaAddressRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetSolanaBalanceRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SolanaTransactionStatus {
+  Failed(String),
+  Confirmed(u64),
+  Submitted(String),
+  Pending,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SolanaTransactionRecord {
+  pub id: u64,
+  pub to: String,
+  pub status: SolanaTransactionStatus,
+  pub signature: Option<String>,
+  pub network: Option<String>,
+  pub annotation: Option<TxAnnotation>,
+  pub timestamp: u64,
+  pub amount_lamports: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SolanaWalletInfo { pub network: String, pub address: String }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetSolanaWalletInfoRet { Ok(SolanaWalletInfo), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SpendingBudget {
+  pub remaining_today: Option<candid::Nat>,
+  pub limit: SpendingLimit,
+  pub spent_today: candid::Nat,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetSpendingBudgetsRet { Ok(Vec<SpendingBudget>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetSplTokenBalanceRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SponsoredCallKind { Forwarder, Direct }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SponsoredOperation {
+  pub forwarder: Option<String>,
+  pub kind: SponsoredCallKind,
+  pub name: String,
+  pub enabled: bool,
+  pub target: String,
+  pub chain_id: u64,
+  pub selectors: Vec<String>,
+  pub max_per_user_per_day: u32,
+  pub max_gas: u64,
+  pub daily_budget_wei: candid::Nat,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SponsorshipPolicy { pub max_gas_price_wei: u64, pub enabled: bool }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SponsorshipStatus {
+  pub operations: Vec<SponsoredOperation>,
+  pub spent_today_wei: Vec<(String,candid::Nat,)>,
+  pub policy: SponsorshipPolicy,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum StorageResidency { Heap, Stable }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct StorageConfig { pub conversations: StorageResidency }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct StorageStats {
+  pub stable_conversations: u64,
+  pub stable_store_bytes: u64,
+  pub memories: u64,
+  pub heap_conversations: u64,
+  pub config: StorageConfig,
+  pub stable_store_live_bytes: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetStorageStatsRet { Ok(StorageStats), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct QuestionCluster {
+  pub question: String,
+  pub askers: u64,
+  pub count: u64,
+  pub last_asked: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetTopQuestionsRet { Ok(Vec<QuestionCluster>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum TransactionStatus { Failed(String), Completed, Pending }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum TransactionType { Send, Receive }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransactionRecord {
+  pub id: u64,
+  pub to: Option<String>,
+  pub status: TransactionStatus,
+  pub from: Option<String>,
+  pub memo: u64,
+  pub annotation: Option<TxAnnotation>,
+  pub timestamp: u64,
+  pub tx_type: TransactionType,
+  pub amount: u64,
+  pub block_height: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TwitterAccountStatus {
+  pub last_mention_id: Option<String>,
+  pub user_id: Option<String>,
+  pub label: String,
+  pub calls_this_hour: u32,
+  pub last_poll_time: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetTwitterAccountsRet { Ok(Vec<TwitterAccountStatus>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetTwitterOauth2StatusRet { Ok(TwitterOAuth2Status), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SearchEngagement { Reply, Monitor, ReplyWhenAddressed }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TwitterSearch {
+  pub query: String,
+  pub engagement: SearchEngagement,
+  pub max_replies_per_day: Option<u32>,
+  pub include_retweets: bool,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetTwitterSearchesRet { Ok(Vec<TwitterSearch>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum DepositStatus {
+  Swept{ tx_id: String },
+  Ignored,
+  Flagged,
+  Adopted(String),
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct UnexpectedDeposit {
+  pub id: u64,
+  pub status: DepositStatus,
+  pub token: String,
+  pub detected_at: u64,
+  pub from: Option<String>,
+  pub chain: ChainRef,
+  pub tx_hash: Option<String>,
+  pub amount: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct DexSwapQuote {
+  pub token_in: String,
+  pub amount_out: String,
+  pub chain_id: u64,
+  pub price_impact: String,
+  pub amount_in: String,
+  pub token_out: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetUniswapQuoteRet { Ok(DexSwapQuote), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetUsageStatsRet { Ok(UsageStats), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct WalletInfo {
+  pub icp_address: String,
+  pub last_balance_update: u64,
+  pub principal_id: String,
+  pub icp_balance: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetWalletStatusRet { Ok(WalletInfo), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct WatchdogIncident {
+  pub timer: WatchedTimer,
+  pub detected_at: u64,
+  pub silent_for_seconds: u64,
+  pub restarted: bool,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TimerHealth {
+  pub timer: WatchedTimer,
+  pub armed: bool,
+  pub armed_at: u64,
+  pub stalled: bool,
+  pub interval_seconds: u64,
+  pub last_beat: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct WatchdogConfig {
+  pub auto_restart: bool,
+  pub admin_discord_user_ids: Vec<String>,
+  pub alert_via_discord: bool,
+  pub enabled: bool,
+  pub missed_ticks: u32,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct WatchdogStatus {
+  pub incidents: Vec<WatchdogIncident>,
+  pub timers: Vec<TimerHealth>,
+  pub last_check_at: u64,
+  pub config: WatchdogConfig,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetWatchdogStatusRet { Ok(WatchdogStatus), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetWebhookSigningPublicKeyRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetWorkspaceRet { Ok(WorkspaceInfo), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetWorkspaceTransactionsRet { Ok(Vec<TransactionRecord>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct WorkspaceQuotas {
+  pub max_transactions: Option<u64>,
+  pub max_llm_tokens: Option<u64>,
+  pub max_outcalls: Option<u64>,
+  pub max_posts: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct WorkspacePlan {
+  pub base_fee: u64,
+  pub price_per_post: u64,
+  pub token_symbol: String,
+  pub name: String,
+  pub price_per_outcall: u64,
+  pub ledger: Principal,
+  pub price_per_1k_tokens: u64,
+  pub price_per_transaction: u64,
+  pub quotas: WorkspaceQuotas,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct WorkspaceMeter {
+  pub outcalls: u64,
+  pub llm_tokens: u64,
+  pub transactions: u64,
+  pub posts: u64,
+  pub llm_requests: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct WorkspaceUsage {
+  pub period_end: u64,
+  pub plan: Option<WorkspacePlan>,
+  pub period_start: u64,
+  pub open_invoices: u32,
+  pub usage: WorkspaceMeter,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetWorkspaceUsageRet { Ok(WorkspaceUsage), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct GatewayRequest {
+  pub url: String,
+  pub method: String,
+  pub body: serde_bytes::ByteBuf,
+  pub headers: Vec<(String,String,)>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct GatewayResponse {
+  pub body: serde_bytes::ByteBuf,
+  pub headers: Vec<(String,String,)>,
+  pub upgrade: Option<bool>,
+  pub status_code: u16,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SupportedStandard { pub url: String, pub name: String }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum Icrc7Value {
+  Nat(candid::Nat),
+  Blob(serde_bytes::ByteBuf),
+  Text(String),
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct Icrc7TransferArg {
+  pub to: Account,
+  pub token_id: candid::Nat,
+  pub memo: Option<serde_bytes::ByteBuf>,
+  pub from_subaccount: Option<serde_bytes::ByteBuf>,
+  pub created_at_time: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum Icrc7TransferError {
+  GenericError{ message: String, error_code: candid::Nat },
+  Duplicate{ duplicate_of: candid::Nat },
+  NonExistingTokenId,
+  Unauthorized,
+  CreatedInFuture{ ledger_time: u64 },
+  InvalidRecipient,
+  GenericBatchError{ message: String, error_code: candid::Nat },
+  TooOld,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum Icrc7TransferResult { Ok(candid::Nat), Err(Icrc7TransferError) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum IgnoreUnexpectedDepositRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ImportCharacterJsonRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ImportConversationRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ImportPersonaBundleRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ImportPersonaBundleFromRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum InitSolanaWalletRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum InviteToRoomRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum JoinRoomRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum LeaveRoomRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum AdminNoteKind { ApiTier, Recovery, Runbook, Other }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct AdminNote {
+  pub id: u64,
+  pub encrypted_body: serde_bytes::ByteBuf,
+  pub updated_at: u64,
+  pub updated_by: Principal,
+  pub encrypted_title: serde_bytes::ByteBuf,
+  pub kind: AdminNoteKind,
+  pub min_role: Role,
+  pub created_at: u64,
+  pub created_by: Principal,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ListAdminNotesRet { Ok(Vec<AdminNote>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum InvoiceStatus {
+  Paying,
+  Open,
+  Paid{ block_index: candid::Nat, paid_at: u64 },
+  Void,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct InvoiceLine {
+  pub description: String,
+  pub quantity: u64,
+  pub amount: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct WorkspaceInvoice {
+  pub id: u64,
+  pub status: InvoiceStatus,
+  pub total: u64,
+  pub period_end: u64,
+  pub issued_at: u64,
+  pub workspace_id: String,
+  pub token_symbol: String,
+  pub plan: String,
+  pub period_start: u64,
+  pub lines: Vec<InvoiceLine>,
+  pub ledger: Principal,
+  pub usage: WorkspaceMeter,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ListAllWorkspaceInvoicesRet { Ok(Vec<WorkspaceInvoice>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct CharacterNft {
+  pub character_id: String,
+  pub token_id: u64,
+  pub owner: Account,
+  pub created_at: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ConversationSummary {
+  pub updated_at: u64,
+  pub escalated: bool,
+  pub owner: Principal,
+  pub character: String,
+  pub created_at: u64,
+  pub message_count: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ConversationPage {
+  pub conversations: Vec<ConversationSummary>,
+  pub next_cursor: Option<Principal>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ListConversationsRet { Ok(ConversationPage), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum TopUpStatus {
+  Refunded{ reason: String },
+  Credited{ cycles: candid::Nat },
+  Notifying,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct CyclesTopUp {
+  pub last_error: Option<String>,
+  pub status: TopUpStatus,
+  pub block_index: u64,
+  pub automatic: bool,
+  pub created_at: u64,
+  pub amount_e8s: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ListCyclesTopUpsRet { Ok(Vec<CyclesTopUp>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ListIdempotencyKeysRet { Ok(Vec<IdempotencyRecord>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum JobStatus { Queued, Dead, Running }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum JobKind {
+  PublishPosts,
+  ConfirmTransaction{ wallet: TxWallet, record_id: u64 },
+  PostSeries{ series_id: u64 },
+  RefreshPortfolio,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum JobPriority { Low, High, Normal }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct Job {
+  pub id: u64,
+  pub last_error: Option<String>,
+  pub status: JobStatus,
+  pub run_at: u64,
+  pub kind: JobKind,
+  pub attempts: u32,
+  pub created_at: u64,
+  pub max_attempts: u32,
+  pub priority: JobPriority,
+  pub started_at: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct JobQueueStatus {
+  pub jobs: Vec<Job>,
+  pub dead_letter: Vec<Job>,
+  pub next_run_at: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ListJobsRet { Ok(JobQueueStatus), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct LlmBackendInfo {
+  pub provider: LlmProvider,
+  pub models: Vec<String>,
+  pub default_model: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct RoomMember {
+  pub principal: Principal,
+  pub display_name: String,
+  pub joined_at: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct RoomInfo {
+  pub id: u64,
+  pub updated_at: u64,
+  pub members: Vec<RoomMember>,
+  pub owner: Principal,
+  pub character: String,
+  pub name: String,
+  pub open: bool,
+  pub created_at: u64,
+  pub message_count: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct PollRecording {
+  pub id: u64,
+  pub len: u64,
+  pub source: String,
+  pub offset: u64,
+  pub platform: SocialPlatform,
+  pub recorded_at: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ListPollRecordingsRet { Ok(Vec<PollRecording>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ListPostDraftsRet { Ok(Vec<PostDraft>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct RestTokenInfo {
+  pub id: u64,
+  pub principal: Principal,
+  pub created_at: u64,
+  pub label: String,
+  pub last_used: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ListRestTokensRet { Ok(Vec<RestTokenInfo>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SponsoredTx {
+  pub id: u64,
+  pub max_cost_wei: candid::Nat,
+  pub error: Option<String>,
+  pub chain_id: u64,
+  pub user_address: String,
+  pub operation: String,
+  pub gas_limit: u64,
+  pub caller: Principal,
+  pub tx_hash: Option<String>,
+  pub submitted_at: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ListWorkspaceInvoicesRet { Ok(Vec<WorkspaceInvoice>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum MintCharacterNftRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum MuteSocialUserRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum PauseRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum PausePostSeriesRet { Ok(PostSeries), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum PayWorkspaceInvoiceRet { Ok(WorkspaceInvoice), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum PlanSendRet { Ok(SendPlan), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum DiscordReport { Status, Portfolio }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum PostDiscordReportRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum PostNowRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum PostThreadRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum PreviewOutputPipelineRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum PromoteRolloutRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ProposeAdminRet { Ok(PendingAdminTransfer), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum PublishPersonaBundleRet { Ok(SignedPersonaBundle), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum TxState { Failed, Confirmed, Submitted, Pending }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TxCursor { pub id: u64, pub timestamp: u64, pub wallet: TxWallet }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TxQuery {
+  pub states: Vec<TxState>,
+  pub from_time: Option<u64>,
+  pub asset: Option<String>,
+  pub to_time: Option<u64>,
+  pub min_amount: Option<String>,
+  pub cursor: Option<TxCursor>,
+  pub limit: Option<u32>,
+  pub counterparty: Option<String>,
+  pub wallets: Vec<TxWallet>,
+  pub notes: Option<TxFilter>,
+  pub max_amount: Option<String>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct WalletTx {
+  pub id: u64,
+  pub explorer_url: Option<String>,
+  pub token: Option<String>,
+  pub tx_id: Option<String>,
+  pub asset_known: bool,
+  pub chain: ChainRef,
+  pub error: Option<String>,
+  pub counterparty: String,
+  pub state: TxState,
+  pub annotation: Option<TxAnnotation>,
+  pub timestamp: u64,
+  pub wallet: TxWallet,
+  pub amount: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TxPage {
+  pub next_cursor: Option<TxCursor>,
+  pub transactions: Vec<WalletTx>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum QueryTransactionsRet { Ok(TxPage), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RateConversationRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RefreshAutoPostVariantStatsRet {
+  Ok(AutoPostVariantReport),
+  Err(String),
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RefreshCkbtcWithdrawalsRet {
+  Ok(Vec<CkbtcTransactionRecord>),
+  Err(String),
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RefreshCkethWithdrawalsRet { Ok(Vec<CkethWithdrawal>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RefreshIcrc2AllowancesRet { Ok(Vec<Icrc2Allowance>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RefreshPlatformMetadataRet { Ok(PlatformMetadata), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RegisterChainDescriptorRet { Ok(ChainDescriptor), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RejectAutoPostRet { Ok(AutoPostDraftStatus), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RejectFaqDraftRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ReleaseQuarantinedRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RememberRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RemoveAddressBookEntryRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RemoveAllowedPrincipalRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RemoveAllowedSocialUserRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RemoveAssetRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RemoveChainDescriptorRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RemoveIcrc1TokenRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RemoveSpendingLimitRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RemoveSponsoredOperationRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RemoveTwitterAccountRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ReplayOutcome {
+  Failed{ error: String },
+  Gated{ held: bool, sentiment: MessageSentiment },
+  NotAllowed,
+  Held{ content: String, reason: String },
+  NotAddressed,
+  Reply{ content: String },
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ReplayedMessage {
+  pub message: IncomingMessage,
+  pub outcome: ReplayOutcome,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ReplayPollRecordingRet { Ok(Vec<ReplayedMessage>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ResetOutcallBreakerRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ResetSolanaWalletRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ResumeRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ResumeEventSubscriptionRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ResumePostSeriesRet { Ok(PostSeries), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RetrieveBtcRet { Ok(CkbtcTransactionRecord), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RetryDeadJobRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RetryQueuedSendsRet { Ok(Vec<QueuedSend>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RevokeRestTokenRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RollbackRolloutRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RotateDiscordTokenRet { Ok(SecretMetadata), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RotateOpenaiApiKeyRet { Ok(SecretMetadata), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RotateSecretsKeyRet { Ok(KeyRotation), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum RotateTwitterCredentialsRet { Ok(SecretMetadata), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SaveAddressBookEntryRet { Ok(AddressBookEntry), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SaveAdminNoteRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ScanForUnexpectedDepositsRet { Ok(u32), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SchedulePostRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SchedulePostDraftRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ScheduleRecurringPostRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ConversationMatch {
+  pub owner: Principal,
+  pub message: Message,
+  pub index: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SearchConversationsRet { Ok(Vec<ConversationMatch>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SendOutcome { Queued(QueuedSend), Sent(SendReceipt) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SendRet { Ok(SendOutcome), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SendCampaignUpdateRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SendErc20Ret { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SendEvmNativeRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SendIcpRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SendIcrc1Ret { Ok(candid::Nat), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SendSolanaRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SendSplTokenRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SendWorkspaceIcpRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetAccessModeRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetAutoBlockConfigRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetAutoPostApprovalRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetAutoPostTopicsRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetAutoPostVariantsRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetAutoReplyRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetBillingConfigRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetBridgePolicyRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetChatCommandPolicyRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetConversationPrivacyRet { Ok(ConversationPrivacy), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetCyclesAlertConfigRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetCyclesTierConfigRet { Ok(CyclesTier), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetCyclesTopUpConfigRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetDiscordChannelProfilesRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetDuplicateGuardConfigRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetEnabledPlatformsRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetEventFeedPublicRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetExplorerTemplateRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetFinancialDisclosureConfigRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetGovernancePrincipalRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetKeyRotationConfigRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetLlmProviderRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetMemoryConfigRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetModerationConfigRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetNotificationPreferencesRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetOutcallPolicyRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetOutputPipelineRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetPersonaAssetsRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetPollRecorderConfigRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetPrivacyPolicyRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetProvenanceConfigRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetReplyRulesRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetRoleRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetRolloutPercentRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetSentimentGateConfigRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetSpendingLimitRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetSponsoredOperationRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetSponsorshipPolicyRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum StorageSubsystem { Conversations }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetStorageResidencyRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetTransactionTagsRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetTwitterOauth2TokenProxyRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetTwitterSearchesRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetWatchdogConfigRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetWorkspaceCharacterRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetWorkspaceMemberRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetWorkspacePlanRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SetWorkspacePolicyRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SponsoredCallRequest {
+  pub gas: Option<u64>,
+  pub signature: Option<String>,
+  pub data: String,
+  pub deadline: Option<u64>,
+  pub user_address: String,
+  pub operation: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SponsorOperationRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum StartAutoPostingRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum StartBridgeMonitorRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum StartChainMaintenanceRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum StartCharacterRolloutRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum StartDepositMonitorRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum StartFaqDistillationRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum StartSocialPollingRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum StartTwitterOauth2Ret { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum StopAutoPostingRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum StopBridgeMonitorRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum StopDepositMonitorRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum StopFaqDistillationRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum StopSocialPollingRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum StoreAnthropicApiKeyRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum StoreCmsWebhookSecretRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum StoreEncryptedApiKeyRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum StoreGeminiApiKeyRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SubmitChatRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SubscribeEventsRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum SweepUnexpectedDepositRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ReplyRuleProbe {
+  pub content: String,
+  pub author_name: String,
+  pub author_followers: Option<u64>,
+  pub platform: SocialPlatform,
+  pub author_id: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ReplyDecision {
+  pub rule: Option<String>,
+  pub reply: bool,
+  pub reason: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum TestReplyRulesRet { Ok(ReplyDecision), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum TopUpCyclesRet { Ok(CyclesTopUp), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformEmbeddingResponseArgResponseHeadersItem {
+  pub value: String,
+  pub name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformEmbeddingResponseArgResponse {
+  pub status: candid::Nat,
+  pub body: serde_bytes::ByteBuf,
+  pub headers: Vec<TransformEmbeddingResponseArgResponseHeadersItem>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformEmbeddingResponseArg {
+  pub context: serde_bytes::ByteBuf,
+  pub response: TransformEmbeddingResponseArgResponse,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformEmbeddingResponseRetHeadersItem {
+  pub value: String,
+  pub name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformEmbeddingResponseRet {
+  pub status: candid::Nat,
+  pub body: serde_bytes::ByteBuf,
+  pub headers: Vec<TransformEmbeddingResponseRetHeadersItem>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformEvmResponseArgResponseHeadersItem {
+  pub value: String,
+  pub name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformEvmResponseArgResponse {
+  pub status: candid::Nat,
+  pub body: serde_bytes::ByteBuf,
+  pub headers: Vec<TransformEvmResponseArgResponseHeadersItem>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformEvmResponseArg {
+  pub context: serde_bytes::ByteBuf,
+  pub response: TransformEvmResponseArgResponse,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformEvmResponseRetHeadersItem {
+  pub value: String,
+  pub name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformEvmResponseRet {
+  pub status: candid::Nat,
+  pub body: serde_bytes::ByteBuf,
+  pub headers: Vec<TransformEvmResponseRetHeadersItem>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformFarcasterResponseArgResponseHeadersItem {
+  pub value: String,
+  pub name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformFarcasterResponseArgResponse {
+  pub status: candid::Nat,
+  pub body: serde_bytes::ByteBuf,
+  pub headers: Vec<TransformFarcasterResponseArgResponseHeadersItem>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformFarcasterResponseArg {
+  pub context: serde_bytes::ByteBuf,
+  pub response: TransformFarcasterResponseArgResponse,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformFarcasterResponseRetHeadersItem {
+  pub value: String,
+  pub name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformFarcasterResponseRet {
+  pub status: candid::Nat,
+  pub body: serde_bytes::ByteBuf,
+  pub headers: Vec<TransformFarcasterResponseRetHeadersItem>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformLlmResponseArgResponseHeadersItem {
+  pub value: String,
+  pub name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformLlmResponseArgResponse {
+  pub status: candid::Nat,
+  pub body: serde_bytes::ByteBuf,
+  pub headers: Vec<TransformLlmResponseArgResponseHeadersItem>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformLlmResponseArg {
+  pub context: serde_bytes::ByteBuf,
+  pub response: TransformLlmResponseArgResponse,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformLlmResponseRetHeadersItem {
+  pub value: String,
+  pub name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformLlmResponseRet {
+  pub status: candid::Nat,
+  pub body: serde_bytes::ByteBuf,
+  pub headers: Vec<TransformLlmResponseRetHeadersItem>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformMastodonResponseArgResponseHeadersItem {
+  pub value: String,
+  pub name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformMastodonResponseArgResponse {
+  pub status: candid::Nat,
+  pub body: serde_bytes::ByteBuf,
+  pub headers: Vec<TransformMastodonResponseArgResponseHeadersItem>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformMastodonResponseArg {
+  pub context: serde_bytes::ByteBuf,
+  pub response: TransformMastodonResponseArgResponse,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformMastodonResponseRetHeadersItem {
+  pub value: String,
+  pub name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformMastodonResponseRet {
+  pub status: candid::Nat,
+  pub body: serde_bytes::ByteBuf,
+  pub headers: Vec<TransformMastodonResponseRetHeadersItem>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformOpenaiResponseArgResponseHeadersItem {
+  pub value: String,
+  pub name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformOpenaiResponseArgResponse {
+  pub status: candid::Nat,
+  pub body: serde_bytes::ByteBuf,
+  pub headers: Vec<TransformOpenaiResponseArgResponseHeadersItem>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformOpenaiResponseArg {
+  pub context: serde_bytes::ByteBuf,
+  pub response: TransformOpenaiResponseArgResponse,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformOpenaiResponseRetHeadersItem {
+  pub value: String,
+  pub name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformOpenaiResponseRet {
+  pub status: candid::Nat,
+  pub body: serde_bytes::ByteBuf,
+  pub headers: Vec<TransformOpenaiResponseRetHeadersItem>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformRedditResponseArgResponseHeadersItem {
+  pub value: String,
+  pub name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformRedditResponseArgResponse {
+  pub status: candid::Nat,
+  pub body: serde_bytes::ByteBuf,
+  pub headers: Vec<TransformRedditResponseArgResponseHeadersItem>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformRedditResponseArg {
+  pub context: serde_bytes::ByteBuf,
+  pub response: TransformRedditResponseArgResponse,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformRedditResponseRetHeadersItem {
+  pub value: String,
+  pub name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformRedditResponseRet {
+  pub status: candid::Nat,
+  pub body: serde_bytes::ByteBuf,
+  pub headers: Vec<TransformRedditResponseRetHeadersItem>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformSocialResponseArgResponseHeadersItem {
+  pub value: String,
+  pub name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformSocialResponseArgResponse {
+  pub status: candid::Nat,
+  pub body: serde_bytes::ByteBuf,
+  pub headers: Vec<TransformSocialResponseArgResponseHeadersItem>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformSocialResponseArg {
+  pub context: serde_bytes::ByteBuf,
+  pub response: TransformSocialResponseArgResponse,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformSocialResponseRetHeadersItem {
+  pub value: String,
+  pub name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformSocialResponseRet {
+  pub status: candid::Nat,
+  pub body: serde_bytes::ByteBuf,
+  pub headers: Vec<TransformSocialResponseRetHeadersItem>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformSolanaResponseArgResponseHeadersItem {
+  pub value: String,
+  pub name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformSolanaResponseArgResponse {
+  pub status: candid::Nat,
+  pub body: serde_bytes::ByteBuf,
+  pub headers: Vec<TransformSolanaResponseArgResponseHeadersItem>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformSolanaResponseArg {
+  pub context: serde_bytes::ByteBuf,
+  pub response: TransformSolanaResponseArgResponse,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformSolanaResponseRetHeadersItem {
+  pub value: String,
+  pub name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformSolanaResponseRet {
+  pub status: candid::Nat,
+  pub body: serde_bytes::ByteBuf,
+  pub headers: Vec<TransformSolanaResponseRetHeadersItem>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformTwitterOauth2ResponseArgResponseHeadersItem {
+  pub value: String,
+  pub name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformTwitterOauth2ResponseArgResponse {
+  pub status: candid::Nat,
+  pub body: serde_bytes::ByteBuf,
+  pub headers: Vec<TransformTwitterOauth2ResponseArgResponseHeadersItem>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformTwitterOauth2ResponseArg {
+  pub context: serde_bytes::ByteBuf,
+  pub response: TransformTwitterOauth2ResponseArgResponse,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformTwitterOauth2ResponseRetHeadersItem {
+  pub value: String,
+  pub name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransformTwitterOauth2ResponseRet {
+  pub status: candid::Nat,
+  pub body: serde_bytes::ByteBuf,
+  pub headers: Vec<TransformTwitterOauth2ResponseRetHeadersItem>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum TriggerAutoPostRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum TriggerFaqDistillationRet { Ok(u64), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum TriggerPollRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum UnbanPrincipalRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum UnblockSocialUserRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum UnsubscribeEventsRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum UpdateCharacterRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum UpdateCkbtcBalanceRet { Ok(Vec<CkbtcTransactionRecord>), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum UploadTwitterMediaRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ValidateGovernanceActionRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum VerifyAddressBookEntryRet { Ok(AddressBookEntry), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum VerifyDiscordInteractionRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum VoidWorkspaceInvoiceRet { Ok, Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum WithdrawCkerc20Ret { Ok(CkethWithdrawal), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum WithdrawCkethRet { Ok(CkethWithdrawal), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum WorkspaceChatRet { Ok(String), Err(String) }
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum WorkspacePostRet { Ok(String), Err(String) }
+
+pub struct CooClient<T>(pub T);
+impl<T: crate::Transport> CooClient<T> {
+  pub async fn accept_admin(&self) -> Result<AcceptAdminRet> {
+    let args = Encode!()?;
+    let bytes = self.0.update("accept_admin", args).await?;
+    Ok(Decode!(&bytes, AcceptAdminRet)?)
+  }
+  pub async fn add_allowed_principal(&self, arg0: Principal) -> Result<
+    AddAllowedPrincipalRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("add_allowed_principal", args).await?;
+    Ok(Decode!(&bytes, AddAllowedPrincipalRet)?)
+  }
+  pub async fn add_allowed_social_user(&self, arg0: SocialUserRef) -> Result<
+    AddAllowedSocialUserRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("add_allowed_social_user", args).await?;
+    Ok(Decode!(&bytes, AddAllowedSocialUserRet)?)
+  }
+  pub async fn add_event_subscription(
+    &self,
+    arg0: Principal,
+    arg1: String,
+    arg2: EventFilter,
+  ) -> Result<AddEventSubscriptionRet> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.update("add_event_subscription", args).await?;
+    Ok(Decode!(&bytes, AddEventSubscriptionRet)?)
+  }
+  pub async fn add_shared_memory(&self, arg0: String) -> Result<
+    AddSharedMemoryRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("add_shared_memory", args).await?;
+    Ok(Decode!(&bytes, AddSharedMemoryRet)?)
+  }
+  pub async fn adopt_unexpected_deposit(
+    &self,
+    arg0: u64,
+    arg1: String,
+    arg2: u8,
+  ) -> Result<AdoptUnexpectedDepositRet> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.update("adopt_unexpected_deposit", args).await?;
+    Ok(Decode!(&bytes, AdoptUnexpectedDepositRet)?)
+  }
+  pub async fn annotate_transaction(
+    &self,
+    arg0: TxWallet,
+    arg1: u64,
+    arg2: Option<TxAnnotation>,
+  ) -> Result<AnnotateTransactionRet> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.update("annotate_transaction", args).await?;
+    Ok(Decode!(&bytes, AnnotateTransactionRet)?)
+  }
+  pub async fn answer_held_mention(&self, arg0: u64) -> Result<
+    AnswerHeldMentionRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("answer_held_mention", args).await?;
+    Ok(Decode!(&bytes, AnswerHeldMentionRet)?)
+  }
+  pub async fn approve_auto_post(&self, arg0: u64) -> Result<
+    ApproveAutoPostRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("approve_auto_post", args).await?;
+    Ok(Decode!(&bytes, ApproveAutoPostRet)?)
+  }
+  pub async fn approve_faq_draft(
+    &self,
+    arg0: u64,
+    arg1: Option<String>,
+  ) -> Result<ApproveFaqDraftRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("approve_faq_draft", args).await?;
+    Ok(Decode!(&bytes, ApproveFaqDraftRet)?)
+  }
+  pub async fn approve_icrc_2(
+    &self,
+    arg0: Principal,
+    arg1: Account,
+    arg2: candid::Nat,
+    arg3: Option<u64>,
+  ) -> Result<ApproveIcrc2Ret> {
+    let args = Encode!(&arg0, &arg1, &arg2, &arg3)?;
+    let bytes = self.0.update("approve_icrc2", args).await?;
+    Ok(Decode!(&bytes, ApproveIcrc2Ret)?)
+  }
+  pub async fn ban_principal(&self, arg0: Principal) -> Result<
+    BanPrincipalRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("ban_principal", args).await?;
+    Ok(Decode!(&bytes, BanPrincipalRet)?)
+  }
+  pub async fn block_social_user(
+    &self,
+    arg0: SocialPlatform,
+    arg1: String,
+  ) -> Result<BlockSocialUserRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("block_social_user", args).await?;
+    Ok(Decode!(&bytes, BlockSocialUserRet)?)
+  }
+  pub async fn bootstrap_from(&self, arg0: Principal) -> Result<
+    BootstrapFromRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("bootstrap_from", args).await?;
+    Ok(Decode!(&bytes, BootstrapFromRet)?)
+  }
+  pub async fn cancel_admin_transfer(&self) -> Result<CancelAdminTransferRet> {
+    let args = Encode!()?;
+    let bytes = self.0.update("cancel_admin_transfer", args).await?;
+    Ok(Decode!(&bytes, CancelAdminTransferRet)?)
+  }
+  pub async fn cancel_queued_send(&self, arg0: u64) -> Result<
+    CancelQueuedSendRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("cancel_queued_send", args).await?;
+    Ok(Decode!(&bytes, CancelQueuedSendRet)?)
+  }
+  pub async fn cancel_scheduled_post(&self, arg0: u64) -> Result<
+    CancelScheduledPostRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("cancel_scheduled_post", args).await?;
+    Ok(Decode!(&bytes, CancelScheduledPostRet)?)
+  }
+  pub async fn chat(&self, arg0: String) -> Result<ChatRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("chat", args).await?;
+    Ok(Decode!(&bytes, ChatRet)?)
+  }
+  pub async fn chat_as(&self, arg0: String, arg1: String) -> Result<ChatAsRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("chat_as", args).await?;
+    Ok(Decode!(&bytes, ChatAsRet)?)
+  }
+  pub async fn chat_in_room(&self, arg0: u64, arg1: String) -> Result<
+    ChatInRoomRet
+  > {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("chat_in_room", args).await?;
+    Ok(Decode!(&bytes, ChatInRoomRet)?)
+  }
+  pub async fn check_bridge_status(&self, arg0: u64) -> Result<
+    CheckBridgeStatusRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("check_bridge_status", args).await?;
+    Ok(Decode!(&bytes, CheckBridgeStatusRet)?)
+  }
+  pub async fn check_cycles_tier(&self) -> Result<CheckCyclesTierRet> {
+    let args = Encode!()?;
+    let bytes = self.0.update("check_cycles_tier", args).await?;
+    Ok(Decode!(&bytes, CheckCyclesTierRet)?)
+  }
+  pub async fn check_duplicate_content(&self, arg0: String) -> Result<
+    CheckDuplicateContentRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("check_duplicate_content", args).await?;
+    Ok(Decode!(&bytes, CheckDuplicateContentRet)?)
+  }
+  pub async fn check_financial_disclosure(
+    &self,
+    arg0: OutputDestination,
+    arg1: String,
+  ) -> Result<CheckFinancialDisclosureRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.query("check_financial_disclosure", args).await?;
+    Ok(Decode!(&bytes, CheckFinancialDisclosureRet)?)
+  }
+  pub async fn check_icp_balance(&self) -> Result<CheckIcpBalanceRet> {
+    let args = Encode!()?;
+    let bytes = self.0.update("check_icp_balance", args).await?;
+    Ok(Decode!(&bytes, CheckIcpBalanceRet)?)
+  }
+  pub async fn check_icrc_2_allowance(
+    &self,
+    arg0: Principal,
+    arg1: Account,
+  ) -> Result<CheckIcrc2AllowanceRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("check_icrc2_allowance", args).await?;
+    Ok(Decode!(&bytes, CheckIcrc2AllowanceRet)?)
+  }
+  pub async fn check_workspace_balance(&self, arg0: String) -> Result<
+    CheckWorkspaceBalanceRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("check_workspace_balance", args).await?;
+    Ok(Decode!(&bytes, CheckWorkspaceBalanceRet)?)
+  }
+  pub async fn classify_message_sentiment(&self, arg0: String) -> Result<
+    ClassifyMessageSentimentRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("classify_message_sentiment", args).await?;
+    Ok(Decode!(&bytes, ClassifyMessageSentimentRet)?)
+  }
+  pub async fn clear_cms_webhook_secret(&self) -> Result<
+    ClearCmsWebhookSecretRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.update("clear_cms_webhook_secret", args).await?;
+    Ok(Decode!(&bytes, ClearCmsWebhookSecretRet)?)
+  }
+  pub async fn clear_conversation(&self) -> Result<()> {
+    let args = Encode!()?;
+    let bytes = self.0.update("clear_conversation", args).await?;
+    Ok(Decode!(&bytes)?)
+  }
+  pub async fn clear_idempotency_key(&self, arg0: String) -> Result<
+    ClearIdempotencyKeyRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("clear_idempotency_key", args).await?;
+    Ok(Decode!(&bytes, ClearIdempotencyKeyRet)?)
+  }
+  pub async fn clear_notification_preferences(&self) -> Result<bool> {
+    let args = Encode!()?;
+    let bytes = self.0.update("clear_notification_preferences", args).await?;
+    Ok(Decode!(&bytes, bool)?)
+  }
+  pub async fn clear_poll_recordings(&self) -> Result<ClearPollRecordingsRet> {
+    let args = Encode!()?;
+    let bytes = self.0.update("clear_poll_recordings", args).await?;
+    Ok(Decode!(&bytes, ClearPollRecordingsRet)?)
+  }
+  pub async fn collect_icrc_2(
+    &self,
+    arg0: Principal,
+    arg1: Account,
+    arg2: candid::Nat,
+  ) -> Result<CollectIcrc2Ret> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.update("collect_icrc2", args).await?;
+    Ok(Decode!(&bytes, CollectIcrc2Ret)?)
+  }
+  pub async fn compact_stable_storage(&self) -> Result<
+    CompactStableStorageRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.update("compact_stable_storage", args).await?;
+    Ok(Decode!(&bytes, CompactStableStorageRet)?)
+  }
+  pub async fn complete_twitter_oauth_2(
+    &self,
+    arg0: String,
+    arg1: String,
+  ) -> Result<CompleteTwitterOauth2Ret> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("complete_twitter_oauth2", args).await?;
+    Ok(Decode!(&bytes, CompleteTwitterOauth2Ret)?)
+  }
+  pub async fn configure_asset(&self, arg0: AssetConfig) -> Result<
+    ConfigureAssetRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("configure_asset", args).await?;
+    Ok(Decode!(&bytes, ConfigureAssetRet)?)
+  }
+  pub async fn configure_discord(&self, arg0: DiscordConfig) -> Result<
+    ConfigureDiscordRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("configure_discord", args).await?;
+    Ok(Decode!(&bytes, ConfigureDiscordRet)?)
+  }
+  pub async fn configure_evm_chain(&self, arg0: EvmChainConfig) -> Result<
+    ConfigureEvmChainRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("configure_evm_chain", args).await?;
+    Ok(Decode!(&bytes, ConfigureEvmChainRet)?)
+  }
+  pub async fn configure_farcaster(&self, arg0: FarcasterConfig) -> Result<
+    ConfigureFarcasterRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("configure_farcaster", args).await?;
+    Ok(Decode!(&bytes, ConfigureFarcasterRet)?)
+  }
+  pub async fn configure_icrc_1_token(&self, arg0: Icrc1Token) -> Result<
+    ConfigureIcrc1TokenRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("configure_icrc1_token", args).await?;
+    Ok(Decode!(&bytes, ConfigureIcrc1TokenRet)?)
+  }
+  pub async fn configure_mastodon(&self, arg0: MastodonConfig) -> Result<
+    ConfigureMastodonRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("configure_mastodon", args).await?;
+    Ok(Decode!(&bytes, ConfigureMastodonRet)?)
+  }
+  pub async fn configure_reddit(&self, arg0: RedditConfig) -> Result<
+    ConfigureRedditRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("configure_reddit", args).await?;
+    Ok(Decode!(&bytes, ConfigureRedditRet)?)
+  }
+  pub async fn configure_solana_network(
+    &self,
+    arg0: SolanaNetworkConfig,
+  ) -> Result<ConfigureSolanaNetworkRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("configure_solana_network", args).await?;
+    Ok(Decode!(&bytes, ConfigureSolanaNetworkRet)?)
+  }
+  pub async fn configure_support_integration(
+    &self,
+    arg0: SupportIntegrationConfig,
+  ) -> Result<ConfigureSupportIntegrationRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("configure_support_integration", args).await?;
+    Ok(Decode!(&bytes, ConfigureSupportIntegrationRet)?)
+  }
+  pub async fn configure_twitter(&self, arg0: TwitterCredentials) -> Result<
+    ConfigureTwitterRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("configure_twitter", args).await?;
+    Ok(Decode!(&bytes, ConfigureTwitterRet)?)
+  }
+  pub async fn configure_twitter_account(
+    &self,
+    arg0: String,
+    arg1: TwitterCredentials,
+  ) -> Result<ConfigureTwitterAccountRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("configure_twitter_account", args).await?;
+    Ok(Decode!(&bytes, ConfigureTwitterAccountRet)?)
+  }
+  pub async fn configure_workspace_discord(
+    &self,
+    arg0: String,
+    arg1: DiscordConfig,
+  ) -> Result<ConfigureWorkspaceDiscordRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("configure_workspace_discord", args).await?;
+    Ok(Decode!(&bytes, ConfigureWorkspaceDiscordRet)?)
+  }
+  pub async fn configure_workspace_twitter(
+    &self,
+    arg0: String,
+    arg1: TwitterCredentials,
+  ) -> Result<ConfigureWorkspaceTwitterRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("configure_workspace_twitter", args).await?;
+    Ok(Decode!(&bytes, ConfigureWorkspaceTwitterRet)?)
+  }
+  pub async fn create_character(&self, arg0: String, arg1: Character) -> Result<
+    CreateCharacterRet
+  > {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("create_character", args).await?;
+    Ok(Decode!(&bytes, CreateCharacterRet)?)
+  }
+  pub async fn create_rest_token(&self, arg0: String) -> Result<
+    CreateRestTokenRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("create_rest_token", args).await?;
+    Ok(Decode!(&bytes, CreateRestTokenRet)?)
+  }
+  pub async fn create_room(
+    &self,
+    arg0: String,
+    arg1: bool,
+    arg2: Option<String>,
+  ) -> Result<CreateRoomRet> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.update("create_room", args).await?;
+    Ok(Decode!(&bytes, CreateRoomRet)?)
+  }
+  pub async fn create_workspace(
+    &self,
+    arg0: String,
+    arg1: String,
+    arg2: Principal,
+  ) -> Result<CreateWorkspaceRet> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.update("create_workspace", args).await?;
+    Ok(Decode!(&bytes, CreateWorkspaceRet)?)
+  }
+  pub async fn delete_admin_note(&self, arg0: u64) -> Result<
+    DeleteAdminNoteRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("delete_admin_note", args).await?;
+    Ok(Decode!(&bytes, DeleteAdminNoteRet)?)
+  }
+  pub async fn delete_character(&self, arg0: String) -> Result<
+    DeleteCharacterRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("delete_character", args).await?;
+    Ok(Decode!(&bytes, DeleteCharacterRet)?)
+  }
+  pub async fn delete_room(&self, arg0: u64) -> Result<DeleteRoomRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("delete_room", args).await?;
+    Ok(Decode!(&bytes, DeleteRoomRet)?)
+  }
+  pub async fn delete_workspace(&self, arg0: String) -> Result<
+    DeleteWorkspaceRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("delete_workspace", args).await?;
+    Ok(Decode!(&bytes, DeleteWorkspaceRet)?)
+  }
+  pub async fn describe_capabilities(&self) -> Result<Capabilities> {
+    let args = Encode!()?;
+    let bytes = self.0.query("describe_capabilities", args).await?;
+    Ok(Decode!(&bytes, Capabilities)?)
+  }
+  pub async fn describe_transaction(&self, arg0: TxIntent) -> Result<
+    DescribeTransactionRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("describe_transaction", args).await?;
+    Ok(Decode!(&bytes, DescribeTransactionRet)?)
+  }
+  pub async fn discard_dead_job(&self, arg0: u64) -> Result<DiscardDeadJobRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("discard_dead_job", args).await?;
+    Ok(Decode!(&bytes, DiscardDeadJobRet)?)
+  }
+  pub async fn discard_post_draft(&self, arg0: u64) -> Result<
+    DiscardPostDraftRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("discard_post_draft", args).await?;
+    Ok(Decode!(&bytes, DiscardPostDraftRet)?)
+  }
+  pub async fn discard_quarantined(&self, arg0: u64) -> Result<
+    DiscardQuarantinedRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("discard_quarantined", args).await?;
+    Ok(Decode!(&bytes, DiscardQuarantinedRet)?)
+  }
+  pub async fn disconnect_twitter_oauth_2(&self) -> Result<
+    DisconnectTwitterOauth2Ret
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.update("disconnect_twitter_oauth2", args).await?;
+    Ok(Decode!(&bytes, DisconnectTwitterOauth2Ret)?)
+  }
+  pub async fn dismiss_held_mention(&self, arg0: u64) -> Result<
+    DismissHeldMentionRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("dismiss_held_mention", args).await?;
+    Ok(Decode!(&bytes, DismissHeldMentionRet)?)
+  }
+  pub async fn edit_character(&self, arg0: String, arg1: Character) -> Result<
+    EditCharacterRet
+  > {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("edit_character", args).await?;
+    Ok(Decode!(&bytes, EditCharacterRet)?)
+  }
+  pub async fn edit_post_draft(&self, arg0: u64, arg1: String) -> Result<
+    EditPostDraftRet
+  > {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("edit_post_draft", args).await?;
+    Ok(Decode!(&bytes, EditPostDraftRet)?)
+  }
+  pub async fn end_chain_maintenance(&self, arg0: ChainRef) -> Result<
+    EndChainMaintenanceRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("end_chain_maintenance", args).await?;
+    Ok(Decode!(&bytes, EndChainMaintenanceRet)?)
+  }
+  pub async fn end_post_series(&self, arg0: u64) -> Result<EndPostSeriesRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("end_post_series", args).await?;
+    Ok(Decode!(&bytes, EndPostSeriesRet)?)
+  }
+  pub async fn escalate_conversation(
+    &self,
+    arg0: bool,
+    arg1: Option<String>,
+  ) -> Result<EscalateConversationRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("escalate_conversation", args).await?;
+    Ok(Decode!(&bytes, EscalateConversationRet)?)
+  }
+  pub async fn estimate_cost(&self, arg0: CostOperation) -> Result<
+    EstimateCostRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("estimate_cost", args).await?;
+    Ok(Decode!(&bytes, EstimateCostRet)?)
+  }
+  pub async fn execute_governance_action(
+    &self,
+    arg0: GovernanceAction,
+  ) -> Result<ExecuteGovernanceActionRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("execute_governance_action", args).await?;
+    Ok(Decode!(&bytes, ExecuteGovernanceActionRet)?)
+  }
+  pub async fn execute_jupiter_swap(
+    &self,
+    arg0: String,
+    arg1: String,
+    arg2: String,
+    arg3: u64,
+    arg4: Option<u64>,
+    arg5: Option<TxAnnotation>,
+  ) -> Result<ExecuteJupiterSwapRet> {
+    let args = Encode!(&arg0, &arg1, &arg2, &arg3, &arg4, &arg5)?;
+    let bytes = self.0.update("execute_jupiter_swap", args).await?;
+    Ok(Decode!(&bytes, ExecuteJupiterSwapRet)?)
+  }
+  pub async fn execute_lifi_bridge(
+    &self,
+    arg0: u64,
+    arg1: u64,
+    arg2: String,
+    arg3: String,
+    arg4: String,
+    arg5: Option<TxAnnotation>,
+  ) -> Result<ExecuteLifiBridgeRet> {
+    let args = Encode!(&arg0, &arg1, &arg2, &arg3, &arg4, &arg5)?;
+    let bytes = self.0.update("execute_lifi_bridge", args).await?;
+    Ok(Decode!(&bytes, ExecuteLifiBridgeRet)?)
+  }
+  pub async fn execute_uniswap_swap(
+    &self,
+    arg0: u64,
+    arg1: String,
+    arg2: String,
+    arg3: String,
+    arg4: String,
+    arg5: Option<u32>,
+    arg6: Option<TxAnnotation>,
+  ) -> Result<ExecuteUniswapSwapRet> {
+    let args = Encode!(&arg0, &arg1, &arg2, &arg3, &arg4, &arg5, &arg6)?;
+    let bytes = self.0.update("execute_uniswap_swap", args).await?;
+    Ok(Decode!(&bytes, ExecuteUniswapSwapRet)?)
+  }
+  pub async fn export_bootstrap_config(&self) -> Result<
+    ExportBootstrapConfigRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("export_bootstrap_config", args).await?;
+    Ok(Decode!(&bytes, ExportBootstrapConfigRet)?)
+  }
+  pub async fn export_conversation(&self) -> Result<ExportConversationRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("export_conversation", args).await?;
+    Ok(Decode!(&bytes, ExportConversationRet)?)
+  }
+  pub async fn export_transactions(&self, arg0: Option<TxFilter>) -> Result<
+    ExportTransactionsRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("export_transactions", args).await?;
+    Ok(Decode!(&bytes, ExportTransactionsRet)?)
+  }
+  pub async fn forget_my_memories(&self) -> Result<u64> {
+    let args = Encode!()?;
+    let bytes = self.0.update("forget_my_memories", args).await?;
+    Ok(Decode!(&bytes, u64)?)
+  }
+  pub async fn generate_drafts(
+    &self,
+    arg0: String,
+    arg1: u32,
+    arg2: SocialPlatform,
+  ) -> Result<GenerateDraftsRet> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.update("generate_drafts", args).await?;
+    Ok(Decode!(&bytes, GenerateDraftsRet)?)
+  }
+  pub async fn get_access_control(&self) -> Result<GetAccessControlRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_access_control", args).await?;
+    Ok(Decode!(&bytes, GetAccessControlRet)?)
+  }
+  pub async fn get_address_book(&self) -> Result<GetAddressBookRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_address_book", args).await?;
+    Ok(Decode!(&bytes, GetAddressBookRet)?)
+  }
+  pub async fn get_admin_notes_key(
+    &self,
+    arg0: Role,
+    arg1: serde_bytes::ByteBuf,
+  ) -> Result<GetAdminNotesKeyRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("get_admin_notes_key", args).await?;
+    Ok(Decode!(&bytes, GetAdminNotesKeyRet)?)
+  }
+  pub async fn get_admin_notes_verification_key(&self) -> Result<
+    GetAdminNotesVerificationKeyRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.update("get_admin_notes_verification_key", args).await?;
+    Ok(Decode!(&bytes, GetAdminNotesVerificationKeyRet)?)
+  }
+  pub async fn get_admins(&self) -> Result<Vec<Principal>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_admins", args).await?;
+    Ok(Decode!(&bytes, Vec<Principal>)?)
+  }
+  pub async fn get_assets(&self) -> Result<Vec<AssetConfig>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_assets", args).await?;
+    Ok(Decode!(&bytes, Vec<AssetConfig>)?)
+  }
+  pub async fn get_audit_log(&self, arg0: Option<u32>) -> Result<
+    GetAuditLogRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("get_audit_log", args).await?;
+    Ok(Decode!(&bytes, GetAuditLogRet)?)
+  }
+  pub async fn get_auto_block_config(&self) -> Result<GetAutoBlockConfigRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_auto_block_config", args).await?;
+    Ok(Decode!(&bytes, GetAutoBlockConfigRet)?)
+  }
+  pub async fn get_auto_post_approval(&self) -> Result<
+    Option<AutoPostApprovalConfig>
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_auto_post_approval", args).await?;
+    Ok(Decode!(&bytes, Option<AutoPostApprovalConfig>)?)
+  }
+  pub async fn get_auto_post_config(&self) -> Result<Option<AutoPostConfig>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_auto_post_config", args).await?;
+    Ok(Decode!(&bytes, Option<AutoPostConfig>)?)
+  }
+  pub async fn get_auto_post_drafts(&self) -> Result<GetAutoPostDraftsRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_auto_post_drafts", args).await?;
+    Ok(Decode!(&bytes, GetAutoPostDraftsRet)?)
+  }
+  pub async fn get_auto_post_variant_stats(&self) -> Result<
+    GetAutoPostVariantStatsRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_auto_post_variant_stats", args).await?;
+    Ok(Decode!(&bytes, GetAutoPostVariantStatsRet)?)
+  }
+  pub async fn get_auto_post_variants(&self) -> Result<GetAutoPostVariantsRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_auto_post_variants", args).await?;
+    Ok(Decode!(&bytes, GetAutoPostVariantsRet)?)
+  }
+  pub async fn get_billing_config(&self) -> Result<Option<BillingConfig>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_billing_config", args).await?;
+    Ok(Decode!(&bytes, Option<BillingConfig>)?)
+  }
+  pub async fn get_blocked_social_users(&self) -> Result<
+    GetBlockedSocialUsersRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_blocked_social_users", args).await?;
+    Ok(Decode!(&bytes, GetBlockedSocialUsersRet)?)
+  }
+  pub async fn get_bridge_policy(&self) -> Result<BridgePolicy> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_bridge_policy", args).await?;
+    Ok(Decode!(&bytes, BridgePolicy)?)
+  }
+  pub async fn get_bridge_records(&self, arg0: Option<u32>) -> Result<
+    Vec<BridgeRecord>
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("get_bridge_records", args).await?;
+    Ok(Decode!(&bytes, Vec<BridgeRecord>)?)
+  }
+  pub async fn get_btc_address(&self) -> Result<GetBtcAddressRet> {
+    let args = Encode!()?;
+    let bytes = self.0.update("get_btc_address", args).await?;
+    Ok(Decode!(&bytes, GetBtcAddressRet)?)
+  }
+  pub async fn get_cached_portfolio(&self) -> Result<Option<Portfolio>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_cached_portfolio", args).await?;
+    Ok(Decode!(&bytes, Option<Portfolio>)?)
+  }
+  pub async fn get_certified_transaction_history(
+    &self,
+    arg0: TxWallet,
+    arg1: Option<u32>,
+    arg2: Option<TxFilter>,
+  ) -> Result<GetCertifiedTransactionHistoryRet> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.query("get_certified_transaction_history", args).await?;
+    Ok(Decode!(&bytes, GetCertifiedTransactionHistoryRet)?)
+  }
+  pub async fn get_certified_wallet_addresses(&self) -> Result<
+    GetCertifiedWalletAddressesRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_certified_wallet_addresses", args).await?;
+    Ok(Decode!(&bytes, GetCertifiedWalletAddressesRet)?)
+  }
+  pub async fn get_chain_descriptors(&self) -> Result<Vec<ChainDescriptor>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_chain_descriptors", args).await?;
+    Ok(Decode!(&bytes, Vec<ChainDescriptor>)?)
+  }
+  pub async fn get_chain_maintenance(&self) -> Result<Vec<MaintenanceWindow>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_chain_maintenance", args).await?;
+    Ok(Decode!(&bytes, Vec<MaintenanceWindow>)?)
+  }
+  pub async fn get_character(&self) -> Result<Option<Character>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_character", args).await?;
+    Ok(Decode!(&bytes, Option<Character>)?)
+  }
+  pub async fn get_chat_command_policy(&self) -> Result<ChatCommandPolicy> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_chat_command_policy", args).await?;
+    Ok(Decode!(&bytes, ChatCommandPolicy)?)
+  }
+  pub async fn get_chat_result(&self, arg0: u64) -> Result<GetChatResultRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("get_chat_result", args).await?;
+    Ok(Decode!(&bytes, GetChatResultRet)?)
+  }
+  pub async fn get_ckbtc_transaction_history(
+    &self,
+    arg0: Option<u32>,
+  ) -> Result<Vec<CkbtcTransactionRecord>> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("get_ckbtc_transaction_history", args).await?;
+    Ok(Decode!(&bytes, Vec<CkbtcTransactionRecord>)?)
+  }
+  pub async fn get_cketh_withdrawals(&self, arg0: Option<u32>) -> Result<
+    Vec<CkethWithdrawal>
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("get_cketh_withdrawals", args).await?;
+    Ok(Decode!(&bytes, Vec<CkethWithdrawal>)?)
+  }
+  pub async fn get_config(&self) -> Result<Option<Config>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_config", args).await?;
+    Ok(Decode!(&bytes, Option<Config>)?)
+  }
+  pub async fn get_configured_chains(&self) -> Result<Vec<EvmChainConfig>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_configured_chains", args).await?;
+    Ok(Decode!(&bytes, Vec<EvmChainConfig>)?)
+  }
+  pub async fn get_conversation_count(&self) -> Result<u64> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_conversation_count", args).await?;
+    Ok(Decode!(&bytes, u64)?)
+  }
+  pub async fn get_conversation_history(&self) -> Result<Vec<Message>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_conversation_history", args).await?;
+    Ok(Decode!(&bytes, Vec<Message>)?)
+  }
+  pub async fn get_conversation_history_of(
+    &self,
+    arg0: Principal,
+    arg1: Option<u64>,
+    arg2: Option<u32>,
+  ) -> Result<GetConversationHistoryOfRet> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.query("get_conversation_history_of", args).await?;
+    Ok(Decode!(&bytes, GetConversationHistoryOfRet)?)
+  }
+  pub async fn get_conversation_history_page(
+    &self,
+    arg0: Option<u64>,
+    arg1: Option<u32>,
+  ) -> Result<MessagePage> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.query("get_conversation_history_page", args).await?;
+    Ok(Decode!(&bytes, MessagePage)?)
+  }
+  pub async fn get_conversation_privacy(&self) -> Result<ConversationPrivacy> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_conversation_privacy", args).await?;
+    Ok(Decode!(&bytes, ConversationPrivacy)?)
+  }
+  pub async fn get_cycles_stats(&self) -> Result<GetCyclesStatsRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_cycles_stats", args).await?;
+    Ok(Decode!(&bytes, GetCyclesStatsRet)?)
+  }
+  pub async fn get_cycles_tier_status(&self) -> Result<CyclesTierStatus> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_cycles_tier_status", args).await?;
+    Ok(Decode!(&bytes, CyclesTierStatus)?)
+  }
+  pub async fn get_cycles_top_up_config(&self) -> Result<CyclesTopUpConfig> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_cycles_top_up_config", args).await?;
+    Ok(Decode!(&bytes, CyclesTopUpConfig)?)
+  }
+  pub async fn get_discord_channel_profiles(&self) -> Result<
+    GetDiscordChannelProfilesRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_discord_channel_profiles", args).await?;
+    Ok(Decode!(&bytes, GetDiscordChannelProfilesRet)?)
+  }
+  pub async fn get_discord_conversations(&self) -> Result<
+    GetDiscordConversationsRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_discord_conversations", args).await?;
+    Ok(Decode!(&bytes, GetDiscordConversationsRet)?)
+  }
+  pub async fn get_duplicate_guard_config(&self) -> Result<
+    GetDuplicateGuardConfigRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_duplicate_guard_config", args).await?;
+    Ok(Decode!(&bytes, GetDuplicateGuardConfigRet)?)
+  }
+  pub async fn get_erc_20_balance(
+    &self,
+    arg0: u64,
+    arg1: String,
+    arg2: Option<String>,
+  ) -> Result<GetErc20BalanceRet> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.update("get_erc20_balance", args).await?;
+    Ok(Decode!(&bytes, GetErc20BalanceRet)?)
+  }
+  pub async fn get_event_subscriptions(&self) -> Result<
+    Vec<EventSubscription>
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_event_subscriptions", args).await?;
+    Ok(Decode!(&bytes, Vec<EventSubscription>)?)
+  }
+  pub async fn get_events(&self, arg0: u64, arg1: Option<u32>) -> Result<
+    GetEventsRet
+  > {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.query("get_events", args).await?;
+    Ok(Decode!(&bytes, GetEventsRet)?)
+  }
+  pub async fn get_evm_address(&self) -> Result<GetEvmAddressRet> {
+    let args = Encode!()?;
+    let bytes = self.0.update("get_evm_address", args).await?;
+    Ok(Decode!(&bytes, GetEvmAddressRet)?)
+  }
+  pub async fn get_evm_balance(&self, arg0: u64) -> Result<GetEvmBalanceRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("get_evm_balance", args).await?;
+    Ok(Decode!(&bytes, GetEvmBalanceRet)?)
+  }
+  pub async fn get_evm_transaction_history(
+    &self,
+    arg0: Option<u32>,
+    arg1: Option<TxFilter>,
+  ) -> Result<Vec<EvmTransactionRecord>> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.query("get_evm_transaction_history", args).await?;
+    Ok(Decode!(&bytes, Vec<EvmTransactionRecord>)?)
+  }
+  pub async fn get_evm_wallet_info(&self, arg0: u64) -> Result<
+    GetEvmWalletInfoRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("get_evm_wallet_info", args).await?;
+    Ok(Decode!(&bytes, GetEvmWalletInfoRet)?)
+  }
+  pub async fn get_explorer_templates(&self) -> Result<Vec<ExplorerTemplate>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_explorer_templates", args).await?;
+    Ok(Decode!(&bytes, Vec<ExplorerTemplate>)?)
+  }
+  pub async fn get_explorer_url(&self, arg0: ChainRef, arg1: String) -> Result<
+    Option<String>
+  > {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.query("get_explorer_url", args).await?;
+    Ok(Decode!(&bytes, Option<String>)?)
+  }
+  pub async fn get_faq_drafts(&self, arg0: Option<FaqDraftStatus>) -> Result<
+    GetFaqDraftsRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("get_faq_drafts", args).await?;
+    Ok(Decode!(&bytes, GetFaqDraftsRet)?)
+  }
+  pub async fn get_financial_disclosure_config(&self) -> Result<
+    GetFinancialDisclosureConfigRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_financial_disclosure_config", args).await?;
+    Ok(Decode!(&bytes, GetFinancialDisclosureConfigRet)?)
+  }
+  pub async fn get_governance_principal(&self) -> Result<Option<Principal>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_governance_principal", args).await?;
+    Ok(Decode!(&bytes, Option<Principal>)?)
+  }
+  pub async fn get_held_mentions(&self) -> Result<GetHeldMentionsRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_held_mentions", args).await?;
+    Ok(Decode!(&bytes, GetHeldMentionsRet)?)
+  }
+  pub async fn get_icrc_1_balance(&self, arg0: Principal) -> Result<
+    GetIcrc1BalanceRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("get_icrc1_balance", args).await?;
+    Ok(Decode!(&bytes, GetIcrc1BalanceRet)?)
+  }
+  pub async fn get_icrc_1_tokens(&self) -> Result<Vec<Icrc1Token>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_icrc1_tokens", args).await?;
+    Ok(Decode!(&bytes, Vec<Icrc1Token>)?)
+  }
+  pub async fn get_icrc_2_allowances(&self) -> Result<GetIcrc2AllowancesRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_icrc2_allowances", args).await?;
+    Ok(Decode!(&bytes, GetIcrc2AllowancesRet)?)
+  }
+  pub async fn get_incoming_messages(&self, arg0: Option<u32>) -> Result<
+    Vec<IncomingMessage>
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("get_incoming_messages", args).await?;
+    Ok(Decode!(&bytes, Vec<IncomingMessage>)?)
+  }
+  pub async fn get_jupiter_quote(
+    &self,
+    arg0: String,
+    arg1: String,
+    arg2: u64,
+    arg3: Option<u64>,
+  ) -> Result<GetJupiterQuoteRet> {
+    let args = Encode!(&arg0, &arg1, &arg2, &arg3)?;
+    let bytes = self.0.update("get_jupiter_quote", args).await?;
+    Ok(Decode!(&bytes, GetJupiterQuoteRet)?)
+  }
+  pub async fn get_key_rotation_status(&self) -> Result<
+    GetKeyRotationStatusRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_key_rotation_status", args).await?;
+    Ok(Decode!(&bytes, GetKeyRotationStatusRet)?)
+  }
+  pub async fn get_lifi_quote(
+    &self,
+    arg0: u64,
+    arg1: u64,
+    arg2: String,
+    arg3: String,
+    arg4: String,
+  ) -> Result<GetLifiQuoteRet> {
+    let args = Encode!(&arg0, &arg1, &arg2, &arg3, &arg4)?;
+    let bytes = self.0.update("get_lifi_quote", args).await?;
+    Ok(Decode!(&bytes, GetLifiQuoteRet)?)
+  }
+  pub async fn get_memory_config(&self) -> Result<MemoryConfig> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_memory_config", args).await?;
+    Ok(Decode!(&bytes, MemoryConfig)?)
+  }
+  pub async fn get_moderation_config(&self) -> Result<GetModerationConfigRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_moderation_config", args).await?;
+    Ok(Decode!(&bytes, GetModerationConfigRet)?)
+  }
+  pub async fn get_my_billing(&self) -> Result<MyBilling> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_my_billing", args).await?;
+    Ok(Decode!(&bytes, MyBilling)?)
+  }
+  pub async fn get_my_role(&self) -> Result<Option<Role>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_my_role", args).await?;
+    Ok(Decode!(&bytes, Option<Role>)?)
+  }
+  pub async fn get_my_usage(&self, arg0: Option<u32>) -> Result<UsageStats> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("get_my_usage", args).await?;
+    Ok(Decode!(&bytes, UsageStats)?)
+  }
+  pub async fn get_next_auto_post_topic(&self) -> Result<
+    GetNextAutoPostTopicRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_next_auto_post_topic", args).await?;
+    Ok(Decode!(&bytes, GetNextAutoPostTopicRet)?)
+  }
+  pub async fn get_notification_preferences(&self) -> Result<
+    Option<NotificationPreferences>
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_notification_preferences", args).await?;
+    Ok(Decode!(&bytes, Option<NotificationPreferences>)?)
+  }
+  pub async fn get_notification_status(&self) -> Result<
+    GetNotificationStatusRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_notification_status", args).await?;
+    Ok(Decode!(&bytes, GetNotificationStatusRet)?)
+  }
+  pub async fn get_outcall_health(&self) -> Result<GetOutcallHealthRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_outcall_health", args).await?;
+    Ok(Decode!(&bytes, GetOutcallHealthRet)?)
+  }
+  pub async fn get_outcall_policy(&self) -> Result<OutcallPolicy> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_outcall_policy", args).await?;
+    Ok(Decode!(&bytes, OutcallPolicy)?)
+  }
+  pub async fn get_output_pipelines(&self) -> Result<GetOutputPipelinesRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_output_pipelines", args).await?;
+    Ok(Decode!(&bytes, GetOutputPipelinesRet)?)
+  }
+  pub async fn get_pause_status(&self) -> Result<Option<PauseInfo>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_pause_status", args).await?;
+    Ok(Decode!(&bytes, Option<PauseInfo>)?)
+  }
+  pub async fn get_payment_request(
+    &self,
+    arg0: String,
+    arg1: Option<String>,
+  ) -> Result<GetPaymentRequestRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.query("get_payment_request", args).await?;
+    Ok(Decode!(&bytes, GetPaymentRequestRet)?)
+  }
+  pub async fn get_pending_admin_transfer(&self) -> Result<
+    Option<PendingAdminTransfer>
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_pending_admin_transfer", args).await?;
+    Ok(Decode!(&bytes, Option<PendingAdminTransfer>)?)
+  }
+  pub async fn get_pending_chat_sends(&self) -> Result<GetPendingChatSendsRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_pending_chat_sends", args).await?;
+    Ok(Decode!(&bytes, GetPendingChatSendsRet)?)
+  }
+  pub async fn get_persona_assets(&self) -> Result<PersonaAssets> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_persona_assets", args).await?;
+    Ok(Decode!(&bytes, PersonaAssets)?)
+  }
+  pub async fn get_persona_provenance(&self) -> Result<
+    Option<PersonaProvenance>
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_persona_provenance", args).await?;
+    Ok(Decode!(&bytes, Option<PersonaProvenance>)?)
+  }
+  pub async fn get_poll_recorder_config(&self) -> Result<
+    GetPollRecorderConfigRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_poll_recorder_config", args).await?;
+    Ok(Decode!(&bytes, GetPollRecorderConfigRet)?)
+  }
+  pub async fn get_poll_recording(&self, arg0: u64) -> Result<
+    GetPollRecordingRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("get_poll_recording", args).await?;
+    Ok(Decode!(&bytes, GetPollRecordingRet)?)
+  }
+  pub async fn get_portfolio(&self) -> Result<GetPortfolioRet> {
+    let args = Encode!()?;
+    let bytes = self.0.update("get_portfolio", args).await?;
+    Ok(Decode!(&bytes, GetPortfolioRet)?)
+  }
+  pub async fn get_post_queues(&self) -> Result<Vec<PostQueueStatus>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_post_queues", args).await?;
+    Ok(Decode!(&bytes, Vec<PostQueueStatus>)?)
+  }
+  pub async fn get_privacy_policy(&self) -> Result<PrivacyPolicy> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_privacy_policy", args).await?;
+    Ok(Decode!(&bytes, PrivacyPolicy)?)
+  }
+  pub async fn get_provenance(&self, arg0: u64) -> Result<
+    Option<ProvenanceRecord>
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("get_provenance", args).await?;
+    Ok(Decode!(&bytes, Option<ProvenanceRecord>)?)
+  }
+  pub async fn get_provenance_config(&self) -> Result<ProvenanceConfig> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_provenance_config", args).await?;
+    Ok(Decode!(&bytes, ProvenanceConfig)?)
+  }
+  pub async fn get_published_persona_bundle(&self) -> Result<
+    Option<SignedPersonaBundle>
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_published_persona_bundle", args).await?;
+    Ok(Decode!(&bytes, Option<SignedPersonaBundle>)?)
+  }
+  pub async fn get_quarantine(&self) -> Result<GetQuarantineRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_quarantine", args).await?;
+    Ok(Decode!(&bytes, GetQuarantineRet)?)
+  }
+  pub async fn get_queued_sends(&self) -> Result<GetQueuedSendsRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_queued_sends", args).await?;
+    Ok(Decode!(&bytes, GetQueuedSendsRet)?)
+  }
+  pub async fn get_reply_rules(&self) -> Result<GetReplyRulesRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_reply_rules", args).await?;
+    Ok(Decode!(&bytes, GetReplyRulesRet)?)
+  }
+  pub async fn get_revenue_report(&self, arg0: Option<u32>) -> Result<
+    GetRevenueReportRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("get_revenue_report", args).await?;
+    Ok(Decode!(&bytes, GetRevenueReportRet)?)
+  }
+  pub async fn get_roles(&self) -> Result<GetRolesRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_roles", args).await?;
+    Ok(Decode!(&bytes, GetRolesRet)?)
+  }
+  pub async fn get_rollout_reports(&self) -> Result<GetRolloutReportsRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_rollout_reports", args).await?;
+    Ok(Decode!(&bytes, GetRolloutReportsRet)?)
+  }
+  pub async fn get_room_history(&self, arg0: u64) -> Result<GetRoomHistoryRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("get_room_history", args).await?;
+    Ok(Decode!(&bytes, GetRoomHistoryRet)?)
+  }
+  pub async fn get_scheduled_posts(&self) -> Result<Vec<ScheduledPost>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_scheduled_posts", args).await?;
+    Ok(Decode!(&bytes, Vec<ScheduledPost>)?)
+  }
+  pub async fn get_secret_metadata(&self) -> Result<GetSecretMetadataRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_secret_metadata", args).await?;
+    Ok(Decode!(&bytes, GetSecretMetadataRet)?)
+  }
+  pub async fn get_secrets_status(&self) -> Result<GetSecretsStatusRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_secrets_status", args).await?;
+    Ok(Decode!(&bytes, GetSecretsStatusRet)?)
+  }
+  pub async fn get_sentiment_gate_config(&self) -> Result<
+    GetSentimentGateConfigRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_sentiment_gate_config", args).await?;
+    Ok(Decode!(&bytes, GetSentimentGateConfigRet)?)
+  }
+  pub async fn get_social_status(&self) -> Result<SocialStatus> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_social_status", args).await?;
+    Ok(Decode!(&bytes, SocialStatus)?)
+  }
+  pub async fn get_solana_address(&self) -> Result<GetSolanaAddressRet> {
+    let args = Encode!()?;
+    let bytes = self.0.update("get_solana_address", args).await?;
+    Ok(Decode!(&bytes, GetSolanaAddressRet)?)
+  }
+  pub async fn get_solana_balance(&self, arg0: String) -> Result<
+    GetSolanaBalanceRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("get_solana_balance", args).await?;
+    Ok(Decode!(&bytes, GetSolanaBalanceRet)?)
+  }
+  pub async fn get_solana_networks(&self) -> Result<Vec<SolanaNetworkConfig>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_solana_networks", args).await?;
+    Ok(Decode!(&bytes, Vec<SolanaNetworkConfig>)?)
+  }
+  pub async fn get_solana_transaction_history(
+    &self,
+    arg0: Option<u32>,
+    arg1: Option<TxFilter>,
+  ) -> Result<Vec<SolanaTransactionRecord>> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.query("get_solana_transaction_history", args).await?;
+    Ok(Decode!(&bytes, Vec<SolanaTransactionRecord>)?)
+  }
+  pub async fn get_solana_wallet_info(&self, arg0: String) -> Result<
+    GetSolanaWalletInfoRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("get_solana_wallet_info", args).await?;
+    Ok(Decode!(&bytes, GetSolanaWalletInfoRet)?)
+  }
+  pub async fn get_spending_budgets(&self) -> Result<GetSpendingBudgetsRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_spending_budgets", args).await?;
+    Ok(Decode!(&bytes, GetSpendingBudgetsRet)?)
+  }
+  pub async fn get_spl_token_balance(
+    &self,
+    arg0: String,
+    arg1: String,
+    arg2: Option<String>,
+  ) -> Result<GetSplTokenBalanceRet> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.update("get_spl_token_balance", args).await?;
+    Ok(Decode!(&bytes, GetSplTokenBalanceRet)?)
+  }
+  pub async fn get_sponsorship_status(&self) -> Result<SponsorshipStatus> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_sponsorship_status", args).await?;
+    Ok(Decode!(&bytes, SponsorshipStatus)?)
+  }
+  pub async fn get_storage_stats(&self) -> Result<GetStorageStatsRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_storage_stats", args).await?;
+    Ok(Decode!(&bytes, GetStorageStatsRet)?)
+  }
+  pub async fn get_support_system(&self) -> Result<Option<SupportSystem>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_support_system", args).await?;
+    Ok(Decode!(&bytes, Option<SupportSystem>)?)
+  }
+  pub async fn get_support_ticket(&self) -> Result<Option<SupportTicket>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_support_ticket", args).await?;
+    Ok(Decode!(&bytes, Option<SupportTicket>)?)
+  }
+  pub async fn get_top_questions(&self, arg0: u64, arg1: Option<u32>) -> Result<
+    GetTopQuestionsRet
+  > {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.query("get_top_questions", args).await?;
+    Ok(Decode!(&bytes, GetTopQuestionsRet)?)
+  }
+  pub async fn get_transaction_history(
+    &self,
+    arg0: Option<u32>,
+    arg1: Option<TxFilter>,
+  ) -> Result<Vec<TransactionRecord>> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.query("get_transaction_history", args).await?;
+    Ok(Decode!(&bytes, Vec<TransactionRecord>)?)
+  }
+  pub async fn get_transaction_tags(&self) -> Result<Vec<String>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_transaction_tags", args).await?;
+    Ok(Decode!(&bytes, Vec<String>)?)
+  }
+  pub async fn get_twitter_accounts(&self) -> Result<GetTwitterAccountsRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_twitter_accounts", args).await?;
+    Ok(Decode!(&bytes, GetTwitterAccountsRet)?)
+  }
+  pub async fn get_twitter_oauth_2_status(&self) -> Result<
+    GetTwitterOauth2StatusRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_twitter_oauth2_status", args).await?;
+    Ok(Decode!(&bytes, GetTwitterOauth2StatusRet)?)
+  }
+  pub async fn get_twitter_searches(&self) -> Result<GetTwitterSearchesRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_twitter_searches", args).await?;
+    Ok(Decode!(&bytes, GetTwitterSearchesRet)?)
+  }
+  pub async fn get_unexpected_deposits(&self) -> Result<
+    Vec<UnexpectedDeposit>
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_unexpected_deposits", args).await?;
+    Ok(Decode!(&bytes, Vec<UnexpectedDeposit>)?)
+  }
+  pub async fn get_uniswap_quote(
+    &self,
+    arg0: u64,
+    arg1: String,
+    arg2: String,
+    arg3: String,
+    arg4: Option<u32>,
+  ) -> Result<GetUniswapQuoteRet> {
+    let args = Encode!(&arg0, &arg1, &arg2, &arg3, &arg4)?;
+    let bytes = self.0.update("get_uniswap_quote", args).await?;
+    Ok(Decode!(&bytes, GetUniswapQuoteRet)?)
+  }
+  pub async fn get_usage_stats(&self, arg0: Option<u32>) -> Result<
+    GetUsageStatsRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("get_usage_stats", args).await?;
+    Ok(Decode!(&bytes, GetUsageStatsRet)?)
+  }
+  pub async fn get_wallet_address(&self) -> Result<String> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_wallet_address", args).await?;
+    Ok(Decode!(&bytes, String)?)
+  }
+  pub async fn get_wallet_addresses(&self) -> Result<Vec<(String,String,)>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_wallet_addresses", args).await?;
+    Ok(Decode!(&bytes, Vec<(String,String,)>)?)
+  }
+  pub async fn get_wallet_info(&self) -> Result<WalletInfo> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_wallet_info", args).await?;
+    Ok(Decode!(&bytes, WalletInfo)?)
+  }
+  pub async fn get_wallet_status(&self) -> Result<GetWalletStatusRet> {
+    let args = Encode!()?;
+    let bytes = self.0.update("get_wallet_status", args).await?;
+    Ok(Decode!(&bytes, GetWalletStatusRet)?)
+  }
+  pub async fn get_watchdog_status(&self) -> Result<GetWatchdogStatusRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("get_watchdog_status", args).await?;
+    Ok(Decode!(&bytes, GetWatchdogStatusRet)?)
+  }
+  pub async fn get_webhook_signing_public_key(&self) -> Result<
+    GetWebhookSigningPublicKeyRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.update("get_webhook_signing_public_key", args).await?;
+    Ok(Decode!(&bytes, GetWebhookSigningPublicKeyRet)?)
+  }
+  pub async fn get_workspace(&self, arg0: String) -> Result<GetWorkspaceRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("get_workspace", args).await?;
+    Ok(Decode!(&bytes, GetWorkspaceRet)?)
+  }
+  pub async fn get_workspace_transactions(
+    &self,
+    arg0: String,
+    arg1: Option<u32>,
+  ) -> Result<GetWorkspaceTransactionsRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.query("get_workspace_transactions", args).await?;
+    Ok(Decode!(&bytes, GetWorkspaceTransactionsRet)?)
+  }
+  pub async fn get_workspace_usage(&self, arg0: String) -> Result<
+    GetWorkspaceUsageRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("get_workspace_usage", args).await?;
+    Ok(Decode!(&bytes, GetWorkspaceUsageRet)?)
+  }
+  pub async fn health(&self) -> Result<String> {
+    let args = Encode!()?;
+    let bytes = self.0.query("health", args).await?;
+    Ok(Decode!(&bytes, String)?)
+  }
+  pub async fn http_request(&self, arg0: GatewayRequest) -> Result<
+    GatewayResponse
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("http_request", args).await?;
+    Ok(Decode!(&bytes, GatewayResponse)?)
+  }
+  pub async fn http_request_update(&self, arg0: GatewayRequest) -> Result<
+    GatewayResponse
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("http_request_update", args).await?;
+    Ok(Decode!(&bytes, GatewayResponse)?)
+  }
+  pub async fn icrc_10_supported_standards(&self) -> Result<
+    Vec<SupportedStandard>
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("icrc10_supported_standards", args).await?;
+    Ok(Decode!(&bytes, Vec<SupportedStandard>)?)
+  }
+  pub async fn icrc_7_atomic_batch_transfers(&self) -> Result<Option<bool>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("icrc7_atomic_batch_transfers", args).await?;
+    Ok(Decode!(&bytes, Option<bool>)?)
+  }
+  pub async fn icrc_7_balance_of(&self, arg0: Vec<Account>) -> Result<
+    Vec<candid::Nat>
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("icrc7_balance_of", args).await?;
+    Ok(Decode!(&bytes, Vec<candid::Nat>)?)
+  }
+  pub async fn icrc_7_collection_metadata(&self) -> Result<
+    Vec<(String,Icrc7Value,)>
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("icrc7_collection_metadata", args).await?;
+    Ok(Decode!(&bytes, Vec<(String,Icrc7Value,)>)?)
+  }
+  pub async fn icrc_7_default_take_value(&self) -> Result<Option<candid::Nat>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("icrc7_default_take_value", args).await?;
+    Ok(Decode!(&bytes, Option<candid::Nat>)?)
+  }
+  pub async fn icrc_7_description(&self) -> Result<Option<String>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("icrc7_description", args).await?;
+    Ok(Decode!(&bytes, Option<String>)?)
+  }
+  pub async fn icrc_7_max_memo_size(&self) -> Result<Option<candid::Nat>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("icrc7_max_memo_size", args).await?;
+    Ok(Decode!(&bytes, Option<candid::Nat>)?)
+  }
+  pub async fn icrc_7_max_query_batch_size(&self) -> Result<
+    Option<candid::Nat>
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("icrc7_max_query_batch_size", args).await?;
+    Ok(Decode!(&bytes, Option<candid::Nat>)?)
+  }
+  pub async fn icrc_7_max_take_value(&self) -> Result<Option<candid::Nat>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("icrc7_max_take_value", args).await?;
+    Ok(Decode!(&bytes, Option<candid::Nat>)?)
+  }
+  pub async fn icrc_7_max_update_batch_size(&self) -> Result<
+    Option<candid::Nat>
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.query("icrc7_max_update_batch_size", args).await?;
+    Ok(Decode!(&bytes, Option<candid::Nat>)?)
+  }
+  pub async fn icrc_7_name(&self) -> Result<String> {
+    let args = Encode!()?;
+    let bytes = self.0.query("icrc7_name", args).await?;
+    Ok(Decode!(&bytes, String)?)
+  }
+  pub async fn icrc_7_owner_of(&self, arg0: Vec<candid::Nat>) -> Result<
+    Vec<Option<Account>>
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("icrc7_owner_of", args).await?;
+    Ok(Decode!(&bytes, Vec<Option<Account>>)?)
+  }
+  pub async fn icrc_7_permitted_drift(&self) -> Result<Option<candid::Nat>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("icrc7_permitted_drift", args).await?;
+    Ok(Decode!(&bytes, Option<candid::Nat>)?)
+  }
+  pub async fn icrc_7_supply_cap(&self) -> Result<Option<candid::Nat>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("icrc7_supply_cap", args).await?;
+    Ok(Decode!(&bytes, Option<candid::Nat>)?)
+  }
+  pub async fn icrc_7_symbol(&self) -> Result<String> {
+    let args = Encode!()?;
+    let bytes = self.0.query("icrc7_symbol", args).await?;
+    Ok(Decode!(&bytes, String)?)
+  }
+  pub async fn icrc_7_token_metadata(&self, arg0: Vec<candid::Nat>) -> Result<
+    Vec<Option<Vec<(String,Icrc7Value,)>>>
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("icrc7_token_metadata", args).await?;
+    Ok(Decode!(&bytes, Vec<Option<Vec<(String,Icrc7Value,)>>>)?)
+  }
+  pub async fn icrc_7_tokens(
+    &self,
+    arg0: Option<candid::Nat>,
+    arg1: Option<candid::Nat>,
+  ) -> Result<Vec<candid::Nat>> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.query("icrc7_tokens", args).await?;
+    Ok(Decode!(&bytes, Vec<candid::Nat>)?)
+  }
+  pub async fn icrc_7_tokens_of(
+    &self,
+    arg0: Account,
+    arg1: Option<candid::Nat>,
+    arg2: Option<candid::Nat>,
+  ) -> Result<Vec<candid::Nat>> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.query("icrc7_tokens_of", args).await?;
+    Ok(Decode!(&bytes, Vec<candid::Nat>)?)
+  }
+  pub async fn icrc_7_total_supply(&self) -> Result<candid::Nat> {
+    let args = Encode!()?;
+    let bytes = self.0.query("icrc7_total_supply", args).await?;
+    Ok(Decode!(&bytes, candid::Nat)?)
+  }
+  pub async fn icrc_7_transfer(&self, arg0: Vec<Icrc7TransferArg>) -> Result<
+    Vec<Option<Icrc7TransferResult>>
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("icrc7_transfer", args).await?;
+    Ok(Decode!(&bytes, Vec<Option<Icrc7TransferResult>>)?)
+  }
+  pub async fn icrc_7_tx_window(&self) -> Result<Option<candid::Nat>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("icrc7_tx_window", args).await?;
+    Ok(Decode!(&bytes, Option<candid::Nat>)?)
+  }
+  pub async fn ignore_unexpected_deposit(&self, arg0: u64) -> Result<
+    IgnoreUnexpectedDepositRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("ignore_unexpected_deposit", args).await?;
+    Ok(Decode!(&bytes, IgnoreUnexpectedDepositRet)?)
+  }
+  pub async fn import_character_json(&self, arg0: String) -> Result<
+    ImportCharacterJsonRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("import_character_json", args).await?;
+    Ok(Decode!(&bytes, ImportCharacterJsonRet)?)
+  }
+  pub async fn import_conversation(&self, arg0: String) -> Result<
+    ImportConversationRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("import_conversation", args).await?;
+    Ok(Decode!(&bytes, ImportConversationRet)?)
+  }
+  pub async fn import_persona_bundle(
+    &self,
+    arg0: SignedPersonaBundle,
+  ) -> Result<ImportPersonaBundleRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("import_persona_bundle", args).await?;
+    Ok(Decode!(&bytes, ImportPersonaBundleRet)?)
+  }
+  pub async fn import_persona_bundle_from(&self, arg0: Principal) -> Result<
+    ImportPersonaBundleFromRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("import_persona_bundle_from", args).await?;
+    Ok(Decode!(&bytes, ImportPersonaBundleFromRet)?)
+  }
+  pub async fn init_solana_wallet(&self) -> Result<InitSolanaWalletRet> {
+    let args = Encode!()?;
+    let bytes = self.0.update("init_solana_wallet", args).await?;
+    Ok(Decode!(&bytes, InitSolanaWalletRet)?)
+  }
+  pub async fn invite_to_room(&self, arg0: u64, arg1: Principal) -> Result<
+    InviteToRoomRet
+  > {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("invite_to_room", args).await?;
+    Ok(Decode!(&bytes, InviteToRoomRet)?)
+  }
+  pub async fn join_room(&self, arg0: u64, arg1: Option<String>) -> Result<
+    JoinRoomRet
+  > {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("join_room", args).await?;
+    Ok(Decode!(&bytes, JoinRoomRet)?)
+  }
+  pub async fn leave_room(&self, arg0: u64) -> Result<LeaveRoomRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("leave_room", args).await?;
+    Ok(Decode!(&bytes, LeaveRoomRet)?)
+  }
+  pub async fn list_admin_notes(&self) -> Result<ListAdminNotesRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("list_admin_notes", args).await?;
+    Ok(Decode!(&bytes, ListAdminNotesRet)?)
+  }
+  pub async fn list_agent_actions(&self) -> Result<Vec<AgentActionInfo>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("list_agent_actions", args).await?;
+    Ok(Decode!(&bytes, Vec<AgentActionInfo>)?)
+  }
+  pub async fn list_all_workspace_invoices(&self, arg0: bool) -> Result<
+    ListAllWorkspaceInvoicesRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("list_all_workspace_invoices", args).await?;
+    Ok(Decode!(&bytes, ListAllWorkspaceInvoicesRet)?)
+  }
+  pub async fn list_character_nfts(&self) -> Result<Vec<CharacterNft>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("list_character_nfts", args).await?;
+    Ok(Decode!(&bytes, Vec<CharacterNft>)?)
+  }
+  pub async fn list_characters(&self) -> Result<Vec<(String,Character,)>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("list_characters", args).await?;
+    Ok(Decode!(&bytes, Vec<(String,Character,)>)?)
+  }
+  pub async fn list_conversations(
+    &self,
+    arg0: Option<Principal>,
+    arg1: Option<u32>,
+  ) -> Result<ListConversationsRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.query("list_conversations", args).await?;
+    Ok(Decode!(&bytes, ListConversationsRet)?)
+  }
+  pub async fn list_cycles_top_ups(&self) -> Result<ListCyclesTopUpsRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("list_cycles_top_ups", args).await?;
+    Ok(Decode!(&bytes, ListCyclesTopUpsRet)?)
+  }
+  pub async fn list_idempotency_keys(&self) -> Result<ListIdempotencyKeysRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("list_idempotency_keys", args).await?;
+    Ok(Decode!(&bytes, ListIdempotencyKeysRet)?)
+  }
+  pub async fn list_jobs(&self) -> Result<ListJobsRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("list_jobs", args).await?;
+    Ok(Decode!(&bytes, ListJobsRet)?)
+  }
+  pub async fn list_llm_backends(&self) -> Result<Vec<LlmBackendInfo>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("list_llm_backends", args).await?;
+    Ok(Decode!(&bytes, Vec<LlmBackendInfo>)?)
+  }
+  pub async fn list_my_rooms(&self) -> Result<Vec<RoomInfo>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("list_my_rooms", args).await?;
+    Ok(Decode!(&bytes, Vec<RoomInfo>)?)
+  }
+  pub async fn list_open_rooms(&self) -> Result<Vec<RoomInfo>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("list_open_rooms", args).await?;
+    Ok(Decode!(&bytes, Vec<RoomInfo>)?)
+  }
+  pub async fn list_poll_recordings(
+    &self,
+    arg0: Option<SocialPlatform>,
+  ) -> Result<ListPollRecordingsRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("list_poll_recordings", args).await?;
+    Ok(Decode!(&bytes, ListPollRecordingsRet)?)
+  }
+  pub async fn list_post_drafts(&self) -> Result<ListPostDraftsRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("list_post_drafts", args).await?;
+    Ok(Decode!(&bytes, ListPostDraftsRet)?)
+  }
+  pub async fn list_post_series(&self) -> Result<Vec<PostSeries>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("list_post_series", args).await?;
+    Ok(Decode!(&bytes, Vec<PostSeries>)?)
+  }
+  pub async fn list_rest_tokens(&self) -> Result<ListRestTokensRet> {
+    let args = Encode!()?;
+    let bytes = self.0.query("list_rest_tokens", args).await?;
+    Ok(Decode!(&bytes, ListRestTokensRet)?)
+  }
+  pub async fn list_sponsored_txs(&self, arg0: Option<u32>) -> Result<
+    Vec<SponsoredTx>
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("list_sponsored_txs", args).await?;
+    Ok(Decode!(&bytes, Vec<SponsoredTx>)?)
+  }
+  pub async fn list_workspace_invoices(&self, arg0: String) -> Result<
+    ListWorkspaceInvoicesRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("list_workspace_invoices", args).await?;
+    Ok(Decode!(&bytes, ListWorkspaceInvoicesRet)?)
+  }
+  pub async fn list_workspaces(&self) -> Result<Vec<WorkspaceInfo>> {
+    let args = Encode!()?;
+    let bytes = self.0.query("list_workspaces", args).await?;
+    Ok(Decode!(&bytes, Vec<WorkspaceInfo>)?)
+  }
+  pub async fn mint_character_nft(
+    &self,
+    arg0: String,
+    arg1: Option<Account>,
+  ) -> Result<MintCharacterNftRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("mint_character_nft", args).await?;
+    Ok(Decode!(&bytes, MintCharacterNftRet)?)
+  }
+  pub async fn mute_social_user(
+    &self,
+    arg0: SocialPlatform,
+    arg1: String,
+    arg2: Option<u64>,
+  ) -> Result<MuteSocialUserRet> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.update("mute_social_user", args).await?;
+    Ok(Decode!(&bytes, MuteSocialUserRet)?)
+  }
+  pub async fn pause(&self, arg0: String) -> Result<PauseRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("pause", args).await?;
+    Ok(Decode!(&bytes, PauseRet)?)
+  }
+  pub async fn pause_post_series(&self, arg0: u64) -> Result<
+    PausePostSeriesRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("pause_post_series", args).await?;
+    Ok(Decode!(&bytes, PausePostSeriesRet)?)
+  }
+  pub async fn pay_workspace_invoice(&self, arg0: String, arg1: u64) -> Result<
+    PayWorkspaceInvoiceRet
+  > {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("pay_workspace_invoice", args).await?;
+    Ok(Decode!(&bytes, PayWorkspaceInvoiceRet)?)
+  }
+  pub async fn plan_send(
+    &self,
+    arg0: String,
+    arg1: Address,
+    arg2: String,
+  ) -> Result<PlanSendRet> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.update("plan_send", args).await?;
+    Ok(Decode!(&bytes, PlanSendRet)?)
+  }
+  pub async fn post_discord_report(
+    &self,
+    arg0: DiscordReport,
+    arg1: Option<String>,
+  ) -> Result<PostDiscordReportRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("post_discord_report", args).await?;
+    Ok(Decode!(&bytes, PostDiscordReportRet)?)
+  }
+  pub async fn post_now(&self, arg0: SocialPlatform, arg1: String) -> Result<
+    PostNowRet
+  > {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("post_now", args).await?;
+    Ok(Decode!(&bytes, PostNowRet)?)
+  }
+  pub async fn post_thread(&self, arg0: Vec<String>) -> Result<PostThreadRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("post_thread", args).await?;
+    Ok(Decode!(&bytes, PostThreadRet)?)
+  }
+  pub async fn preview_output_pipeline(
+    &self,
+    arg0: OutputDestination,
+    arg1: String,
+  ) -> Result<PreviewOutputPipelineRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.query("preview_output_pipeline", args).await?;
+    Ok(Decode!(&bytes, PreviewOutputPipelineRet)?)
+  }
+  pub async fn promote_rollout(&self, arg0: u64) -> Result<PromoteRolloutRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("promote_rollout", args).await?;
+    Ok(Decode!(&bytes, PromoteRolloutRet)?)
+  }
+  pub async fn propose_admin(&self, arg0: Principal) -> Result<
+    ProposeAdminRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("propose_admin", args).await?;
+    Ok(Decode!(&bytes, ProposeAdminRet)?)
+  }
+  pub async fn publish_persona_bundle(&self) -> Result<
+    PublishPersonaBundleRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.update("publish_persona_bundle", args).await?;
+    Ok(Decode!(&bytes, PublishPersonaBundleRet)?)
+  }
+  pub async fn query_transactions(&self, arg0: TxQuery) -> Result<
+    QueryTransactionsRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("query_transactions", args).await?;
+    Ok(Decode!(&bytes, QueryTransactionsRet)?)
+  }
+  pub async fn rate_conversation(&self, arg0: u8) -> Result<
+    RateConversationRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("rate_conversation", args).await?;
+    Ok(Decode!(&bytes, RateConversationRet)?)
+  }
+  pub async fn refresh_auto_post_variant_stats(&self) -> Result<
+    RefreshAutoPostVariantStatsRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.update("refresh_auto_post_variant_stats", args).await?;
+    Ok(Decode!(&bytes, RefreshAutoPostVariantStatsRet)?)
+  }
+  pub async fn refresh_ckbtc_withdrawals(&self) -> Result<
+    RefreshCkbtcWithdrawalsRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.update("refresh_ckbtc_withdrawals", args).await?;
+    Ok(Decode!(&bytes, RefreshCkbtcWithdrawalsRet)?)
+  }
+  pub async fn refresh_cketh_withdrawals(&self) -> Result<
+    RefreshCkethWithdrawalsRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.update("refresh_cketh_withdrawals", args).await?;
+    Ok(Decode!(&bytes, RefreshCkethWithdrawalsRet)?)
+  }
+  pub async fn refresh_icrc_2_allowances(&self) -> Result<
+    RefreshIcrc2AllowancesRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.update("refresh_icrc2_allowances", args).await?;
+    Ok(Decode!(&bytes, RefreshIcrc2AllowancesRet)?)
+  }
+  pub async fn refresh_platform_metadata(&self) -> Result<
+    RefreshPlatformMetadataRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.update("refresh_platform_metadata", args).await?;
+    Ok(Decode!(&bytes, RefreshPlatformMetadataRet)?)
+  }
+  pub async fn register_chain_descriptor(&self, arg0: String) -> Result<
+    RegisterChainDescriptorRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("register_chain_descriptor", args).await?;
+    Ok(Decode!(&bytes, RegisterChainDescriptorRet)?)
+  }
+  pub async fn reject_auto_post(&self, arg0: u64) -> Result<RejectAutoPostRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("reject_auto_post", args).await?;
+    Ok(Decode!(&bytes, RejectAutoPostRet)?)
+  }
+  pub async fn reject_faq_draft(&self, arg0: u64) -> Result<RejectFaqDraftRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("reject_faq_draft", args).await?;
+    Ok(Decode!(&bytes, RejectFaqDraftRet)?)
+  }
+  pub async fn release_quarantined(&self, arg0: u64) -> Result<
+    ReleaseQuarantinedRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("release_quarantined", args).await?;
+    Ok(Decode!(&bytes, ReleaseQuarantinedRet)?)
+  }
+  pub async fn remember(&self, arg0: String) -> Result<RememberRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("remember", args).await?;
+    Ok(Decode!(&bytes, RememberRet)?)
+  }
+  pub async fn remove_address_book_entry(
+    &self,
+    arg0: String,
+    arg1: ChainRef,
+  ) -> Result<RemoveAddressBookEntryRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("remove_address_book_entry", args).await?;
+    Ok(Decode!(&bytes, RemoveAddressBookEntryRet)?)
+  }
+  pub async fn remove_allowed_principal(&self, arg0: Principal) -> Result<
+    RemoveAllowedPrincipalRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("remove_allowed_principal", args).await?;
+    Ok(Decode!(&bytes, RemoveAllowedPrincipalRet)?)
+  }
+  pub async fn remove_allowed_social_user(&self, arg0: SocialUserRef) -> Result<
+    RemoveAllowedSocialUserRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("remove_allowed_social_user", args).await?;
+    Ok(Decode!(&bytes, RemoveAllowedSocialUserRet)?)
+  }
+  pub async fn remove_asset(&self, arg0: String) -> Result<RemoveAssetRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("remove_asset", args).await?;
+    Ok(Decode!(&bytes, RemoveAssetRet)?)
+  }
+  pub async fn remove_chain_descriptor(&self, arg0: u64) -> Result<
+    RemoveChainDescriptorRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("remove_chain_descriptor", args).await?;
+    Ok(Decode!(&bytes, RemoveChainDescriptorRet)?)
+  }
+  pub async fn remove_icrc_1_token(&self, arg0: Principal) -> Result<
+    RemoveIcrc1TokenRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("remove_icrc1_token", args).await?;
+    Ok(Decode!(&bytes, RemoveIcrc1TokenRet)?)
+  }
+  pub async fn remove_spending_limit(
+    &self,
+    arg0: ChainRef,
+    arg1: Option<String>,
+  ) -> Result<RemoveSpendingLimitRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("remove_spending_limit", args).await?;
+    Ok(Decode!(&bytes, RemoveSpendingLimitRet)?)
+  }
+  pub async fn remove_sponsored_operation(&self, arg0: String) -> Result<
+    RemoveSponsoredOperationRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("remove_sponsored_operation", args).await?;
+    Ok(Decode!(&bytes, RemoveSponsoredOperationRet)?)
+  }
+  pub async fn remove_twitter_account(&self, arg0: String) -> Result<
+    RemoveTwitterAccountRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("remove_twitter_account", args).await?;
+    Ok(Decode!(&bytes, RemoveTwitterAccountRet)?)
+  }
+  pub async fn replay_poll_recording(&self, arg0: u64) -> Result<
+    ReplayPollRecordingRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("replay_poll_recording", args).await?;
+    Ok(Decode!(&bytes, ReplayPollRecordingRet)?)
+  }
+  pub async fn reset_outcall_breaker(&self, arg0: String) -> Result<
+    ResetOutcallBreakerRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("reset_outcall_breaker", args).await?;
+    Ok(Decode!(&bytes, ResetOutcallBreakerRet)?)
+  }
+  pub async fn reset_solana_wallet(&self) -> Result<ResetSolanaWalletRet> {
+    let args = Encode!()?;
+    let bytes = self.0.update("reset_solana_wallet", args).await?;
+    Ok(Decode!(&bytes, ResetSolanaWalletRet)?)
+  }
+  pub async fn resume(&self) -> Result<ResumeRet> {
+    let args = Encode!()?;
+    let bytes = self.0.update("resume", args).await?;
+    Ok(Decode!(&bytes, ResumeRet)?)
+  }
+  pub async fn resume_event_subscription(&self, arg0: u64) -> Result<
+    ResumeEventSubscriptionRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("resume_event_subscription", args).await?;
+    Ok(Decode!(&bytes, ResumeEventSubscriptionRet)?)
+  }
+  pub async fn resume_post_series(&self, arg0: u64) -> Result<
+    ResumePostSeriesRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("resume_post_series", args).await?;
+    Ok(Decode!(&bytes, ResumePostSeriesRet)?)
+  }
+  pub async fn retrieve_btc(&self, arg0: String, arg1: u64) -> Result<
+    RetrieveBtcRet
+  > {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("retrieve_btc", args).await?;
+    Ok(Decode!(&bytes, RetrieveBtcRet)?)
+  }
+  pub async fn retry_dead_job(&self, arg0: u64) -> Result<RetryDeadJobRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("retry_dead_job", args).await?;
+    Ok(Decode!(&bytes, RetryDeadJobRet)?)
+  }
+  pub async fn retry_queued_sends(&self) -> Result<RetryQueuedSendsRet> {
+    let args = Encode!()?;
+    let bytes = self.0.update("retry_queued_sends", args).await?;
+    Ok(Decode!(&bytes, RetryQueuedSendsRet)?)
+  }
+  pub async fn revoke_rest_token(&self, arg0: u64) -> Result<
+    RevokeRestTokenRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("revoke_rest_token", args).await?;
+    Ok(Decode!(&bytes, RevokeRestTokenRet)?)
+  }
+  pub async fn rollback_rollout(&self, arg0: u64) -> Result<
+    RollbackRolloutRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("rollback_rollout", args).await?;
+    Ok(Decode!(&bytes, RollbackRolloutRet)?)
+  }
+  pub async fn rotate_discord_token(
+    &self,
+    arg0: serde_bytes::ByteBuf,
+  ) -> Result<RotateDiscordTokenRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("rotate_discord_token", args).await?;
+    Ok(Decode!(&bytes, RotateDiscordTokenRet)?)
+  }
+  pub async fn rotate_openai_api_key(
+    &self,
+    arg0: serde_bytes::ByteBuf,
+  ) -> Result<RotateOpenaiApiKeyRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("rotate_openai_api_key", args).await?;
+    Ok(Decode!(&bytes, RotateOpenaiApiKeyRet)?)
+  }
+  pub async fn rotate_secrets_key(&self, arg0: KeyRotationReason) -> Result<
+    RotateSecretsKeyRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("rotate_secrets_key", args).await?;
+    Ok(Decode!(&bytes, RotateSecretsKeyRet)?)
+  }
+  pub async fn rotate_twitter_credentials(
+    &self,
+    arg0: TwitterCredentials,
+  ) -> Result<RotateTwitterCredentialsRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("rotate_twitter_credentials", args).await?;
+    Ok(Decode!(&bytes, RotateTwitterCredentialsRet)?)
+  }
+  pub async fn save_address_book_entry(
+    &self,
+    arg0: String,
+    arg1: ChainRef,
+    arg2: String,
+  ) -> Result<SaveAddressBookEntryRet> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.update("save_address_book_entry", args).await?;
+    Ok(Decode!(&bytes, SaveAddressBookEntryRet)?)
+  }
+  pub async fn save_admin_note(
+    &self,
+    arg0: Option<u64>,
+    arg1: AdminNoteKind,
+    arg2: Role,
+    arg3: serde_bytes::ByteBuf,
+    arg4: serde_bytes::ByteBuf,
+  ) -> Result<SaveAdminNoteRet> {
+    let args = Encode!(&arg0, &arg1, &arg2, &arg3, &arg4)?;
+    let bytes = self.0.update("save_admin_note", args).await?;
+    Ok(Decode!(&bytes, SaveAdminNoteRet)?)
+  }
+  pub async fn scan_for_unexpected_deposits(&self) -> Result<
+    ScanForUnexpectedDepositsRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.update("scan_for_unexpected_deposits", args).await?;
+    Ok(Decode!(&bytes, ScanForUnexpectedDepositsRet)?)
+  }
+  pub async fn schedule_post(
+    &self,
+    arg0: SocialPlatform,
+    arg1: String,
+    arg2: u64,
+    arg3: Option<PostMetadata>,
+    arg4: Option<PostPriority>,
+    arg5: Option<String>,
+    arg6: Option<String>,
+  ) -> Result<SchedulePostRet> {
+    let args = Encode!(&arg0, &arg1, &arg2, &arg3, &arg4, &arg5, &arg6)?;
+    let bytes = self.0.update("schedule_post", args).await?;
+    Ok(Decode!(&bytes, SchedulePostRet)?)
+  }
+  pub async fn schedule_post_draft(&self, arg0: u64, arg1: u64) -> Result<
+    SchedulePostDraftRet
+  > {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("schedule_post_draft", args).await?;
+    Ok(Decode!(&bytes, SchedulePostDraftRet)?)
+  }
+  pub async fn schedule_recurring_post(
+    &self,
+    arg0: SocialPlatform,
+    arg1: String,
+    arg2: u64,
+    arg3: Option<u64>,
+    arg4: Option<String>,
+  ) -> Result<ScheduleRecurringPostRet> {
+    let args = Encode!(&arg0, &arg1, &arg2, &arg3, &arg4)?;
+    let bytes = self.0.update("schedule_recurring_post", args).await?;
+    Ok(Decode!(&bytes, ScheduleRecurringPostRet)?)
+  }
+  pub async fn search_conversations(
+    &self,
+    arg0: String,
+    arg1: Option<u32>,
+  ) -> Result<SearchConversationsRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.query("search_conversations", args).await?;
+    Ok(Decode!(&bytes, SearchConversationsRet)?)
+  }
+  pub async fn send(
+    &self,
+    arg0: String,
+    arg1: Address,
+    arg2: String,
+    arg3: Option<TxAnnotation>,
+  ) -> Result<SendRet> {
+    let args = Encode!(&arg0, &arg1, &arg2, &arg3)?;
+    let bytes = self.0.update("send", args).await?;
+    Ok(Decode!(&bytes, SendRet)?)
+  }
+  pub async fn send_campaign_update(&self, arg0: String) -> Result<
+    SendCampaignUpdateRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("send_campaign_update", args).await?;
+    Ok(Decode!(&bytes, SendCampaignUpdateRet)?)
+  }
+  pub async fn send_erc_20(
+    &self,
+    arg0: u64,
+    arg1: String,
+    arg2: String,
+    arg3: String,
+    arg4: Option<TxAnnotation>,
+  ) -> Result<SendErc20Ret> {
+    let args = Encode!(&arg0, &arg1, &arg2, &arg3, &arg4)?;
+    let bytes = self.0.update("send_erc20", args).await?;
+    Ok(Decode!(&bytes, SendErc20Ret)?)
+  }
+  pub async fn send_evm_native(
+    &self,
+    arg0: u64,
+    arg1: String,
+    arg2: String,
+    arg3: Option<TxAnnotation>,
+  ) -> Result<SendEvmNativeRet> {
+    let args = Encode!(&arg0, &arg1, &arg2, &arg3)?;
+    let bytes = self.0.update("send_evm_native", args).await?;
+    Ok(Decode!(&bytes, SendEvmNativeRet)?)
+  }
+  pub async fn send_icp(
+    &self,
+    arg0: String,
+    arg1: u64,
+    arg2: Option<u64>,
+    arg3: Option<TxAnnotation>,
+  ) -> Result<SendIcpRet> {
+    let args = Encode!(&arg0, &arg1, &arg2, &arg3)?;
+    let bytes = self.0.update("send_icp", args).await?;
+    Ok(Decode!(&bytes, SendIcpRet)?)
+  }
+  pub async fn send_icrc_1(
+    &self,
+    arg0: Principal,
+    arg1: Account,
+    arg2: candid::Nat,
+  ) -> Result<SendIcrc1Ret> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.update("send_icrc1", args).await?;
+    Ok(Decode!(&bytes, SendIcrc1Ret)?)
+  }
+  pub async fn send_solana(
+    &self,
+    arg0: String,
+    arg1: String,
+    arg2: u64,
+    arg3: Option<TxAnnotation>,
+  ) -> Result<SendSolanaRet> {
+    let args = Encode!(&arg0, &arg1, &arg2, &arg3)?;
+    let bytes = self.0.update("send_solana", args).await?;
+    Ok(Decode!(&bytes, SendSolanaRet)?)
+  }
+  pub async fn send_spl_token(
+    &self,
+    arg0: String,
+    arg1: String,
+    arg2: String,
+    arg3: u64,
+    arg4: Option<TxAnnotation>,
+  ) -> Result<SendSplTokenRet> {
+    let args = Encode!(&arg0, &arg1, &arg2, &arg3, &arg4)?;
+    let bytes = self.0.update("send_spl_token", args).await?;
+    Ok(Decode!(&bytes, SendSplTokenRet)?)
+  }
+  pub async fn send_workspace_icp(
+    &self,
+    arg0: String,
+    arg1: String,
+    arg2: u64,
+    arg3: Option<u64>,
+  ) -> Result<SendWorkspaceIcpRet> {
+    let args = Encode!(&arg0, &arg1, &arg2, &arg3)?;
+    let bytes = self.0.update("send_workspace_icp", args).await?;
+    Ok(Decode!(&bytes, SendWorkspaceIcpRet)?)
+  }
+  pub async fn set_access_mode(&self, arg0: AccessMode) -> Result<
+    SetAccessModeRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_access_mode", args).await?;
+    Ok(Decode!(&bytes, SetAccessModeRet)?)
+  }
+  pub async fn set_auto_block_config(&self, arg0: AutoBlockConfig) -> Result<
+    SetAutoBlockConfigRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_auto_block_config", args).await?;
+    Ok(Decode!(&bytes, SetAutoBlockConfigRet)?)
+  }
+  pub async fn set_auto_post_approval(
+    &self,
+    arg0: Option<AutoPostApprovalConfig>,
+  ) -> Result<SetAutoPostApprovalRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_auto_post_approval", args).await?;
+    Ok(Decode!(&bytes, SetAutoPostApprovalRet)?)
+  }
+  pub async fn set_auto_post_topics(
+    &self,
+    arg0: Vec<AutoPostTopic>,
+    arg1: Vec<String>,
+  ) -> Result<SetAutoPostTopicsRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("set_auto_post_topics", args).await?;
+    Ok(Decode!(&bytes, SetAutoPostTopicsRet)?)
+  }
+  pub async fn set_auto_post_variants(
+    &self,
+    arg0: Vec<AutoPostVariant>,
+  ) -> Result<SetAutoPostVariantsRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_auto_post_variants", args).await?;
+    Ok(Decode!(&bytes, SetAutoPostVariantsRet)?)
+  }
+  pub async fn set_auto_reply(&self, arg0: bool) -> Result<SetAutoReplyRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_auto_reply", args).await?;
+    Ok(Decode!(&bytes, SetAutoReplyRet)?)
+  }
+  pub async fn set_billing_config(&self, arg0: Option<BillingConfig>) -> Result<
+    SetBillingConfigRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_billing_config", args).await?;
+    Ok(Decode!(&bytes, SetBillingConfigRet)?)
+  }
+  pub async fn set_bridge_policy(&self, arg0: BridgePolicy) -> Result<
+    SetBridgePolicyRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_bridge_policy", args).await?;
+    Ok(Decode!(&bytes, SetBridgePolicyRet)?)
+  }
+  pub async fn set_chat_command_policy(
+    &self,
+    arg0: ChatCommandPolicy,
+  ) -> Result<SetChatCommandPolicyRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_chat_command_policy", args).await?;
+    Ok(Decode!(&bytes, SetChatCommandPolicyRet)?)
+  }
+  pub async fn set_conversation_privacy(&self, arg0: bool) -> Result<
+    SetConversationPrivacyRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_conversation_privacy", args).await?;
+    Ok(Decode!(&bytes, SetConversationPrivacyRet)?)
+  }
+  pub async fn set_cycles_alert_config(
+    &self,
+    arg0: CyclesAlertConfig,
+  ) -> Result<SetCyclesAlertConfigRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_cycles_alert_config", args).await?;
+    Ok(Decode!(&bytes, SetCyclesAlertConfigRet)?)
+  }
+  pub async fn set_cycles_tier_config(&self, arg0: CyclesTierConfig) -> Result<
+    SetCyclesTierConfigRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_cycles_tier_config", args).await?;
+    Ok(Decode!(&bytes, SetCyclesTierConfigRet)?)
+  }
+  pub async fn set_cycles_top_up_config(
+    &self,
+    arg0: CyclesTopUpConfig,
+  ) -> Result<SetCyclesTopUpConfigRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_cycles_top_up_config", args).await?;
+    Ok(Decode!(&bytes, SetCyclesTopUpConfigRet)?)
+  }
+  pub async fn set_discord_channel_profiles(
+    &self,
+    arg0: Vec<DiscordChannelProfile>,
+  ) -> Result<SetDiscordChannelProfilesRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_discord_channel_profiles", args).await?;
+    Ok(Decode!(&bytes, SetDiscordChannelProfilesRet)?)
+  }
+  pub async fn set_duplicate_guard_config(
+    &self,
+    arg0: DuplicateGuardConfig,
+  ) -> Result<SetDuplicateGuardConfigRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_duplicate_guard_config", args).await?;
+    Ok(Decode!(&bytes, SetDuplicateGuardConfigRet)?)
+  }
+  pub async fn set_enabled_platforms(
+    &self,
+    arg0: Vec<SocialPlatform>,
+  ) -> Result<SetEnabledPlatformsRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_enabled_platforms", args).await?;
+    Ok(Decode!(&bytes, SetEnabledPlatformsRet)?)
+  }
+  pub async fn set_event_feed_public(&self, arg0: bool) -> Result<
+    SetEventFeedPublicRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_event_feed_public", args).await?;
+    Ok(Decode!(&bytes, SetEventFeedPublicRet)?)
+  }
+  pub async fn set_explorer_template(
+    &self,
+    arg0: ChainRef,
+    arg1: Option<String>,
+  ) -> Result<SetExplorerTemplateRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("set_explorer_template", args).await?;
+    Ok(Decode!(&bytes, SetExplorerTemplateRet)?)
+  }
+  pub async fn set_financial_disclosure_config(
+    &self,
+    arg0: FinancialDisclosureConfig,
+  ) -> Result<SetFinancialDisclosureConfigRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_financial_disclosure_config", args).await?;
+    Ok(Decode!(&bytes, SetFinancialDisclosureConfigRet)?)
+  }
+  pub async fn set_governance_principal(
+    &self,
+    arg0: Option<Principal>,
+  ) -> Result<SetGovernancePrincipalRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_governance_principal", args).await?;
+    Ok(Decode!(&bytes, SetGovernancePrincipalRet)?)
+  }
+  pub async fn set_key_rotation_config(
+    &self,
+    arg0: KeyRotationConfig,
+  ) -> Result<SetKeyRotationConfigRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_key_rotation_config", args).await?;
+    Ok(Decode!(&bytes, SetKeyRotationConfigRet)?)
+  }
+  pub async fn set_llm_provider(&self, arg0: LlmProvider) -> Result<
+    SetLlmProviderRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_llm_provider", args).await?;
+    Ok(Decode!(&bytes, SetLlmProviderRet)?)
+  }
+  pub async fn set_memory_config(&self, arg0: MemoryConfig) -> Result<
+    SetMemoryConfigRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_memory_config", args).await?;
+    Ok(Decode!(&bytes, SetMemoryConfigRet)?)
+  }
+  pub async fn set_moderation_config(&self, arg0: ModerationConfig) -> Result<
+    SetModerationConfigRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_moderation_config", args).await?;
+    Ok(Decode!(&bytes, SetModerationConfigRet)?)
+  }
+  pub async fn set_notification_preferences(
+    &self,
+    arg0: NotificationPreferences,
+  ) -> Result<SetNotificationPreferencesRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_notification_preferences", args).await?;
+    Ok(Decode!(&bytes, SetNotificationPreferencesRet)?)
+  }
+  pub async fn set_outcall_policy(&self, arg0: OutcallPolicy) -> Result<
+    SetOutcallPolicyRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_outcall_policy", args).await?;
+    Ok(Decode!(&bytes, SetOutcallPolicyRet)?)
+  }
+  pub async fn set_output_pipeline(
+    &self,
+    arg0: OutputDestination,
+    arg1: Vec<PostProcessor>,
+  ) -> Result<SetOutputPipelineRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("set_output_pipeline", args).await?;
+    Ok(Decode!(&bytes, SetOutputPipelineRet)?)
+  }
+  pub async fn set_persona_assets(&self, arg0: PersonaAssets) -> Result<
+    SetPersonaAssetsRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_persona_assets", args).await?;
+    Ok(Decode!(&bytes, SetPersonaAssetsRet)?)
+  }
+  pub async fn set_poll_recorder_config(
+    &self,
+    arg0: PollRecorderConfig,
+  ) -> Result<SetPollRecorderConfigRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_poll_recorder_config", args).await?;
+    Ok(Decode!(&bytes, SetPollRecorderConfigRet)?)
+  }
+  pub async fn set_privacy_policy(&self, arg0: PrivacyPolicy) -> Result<
+    SetPrivacyPolicyRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_privacy_policy", args).await?;
+    Ok(Decode!(&bytes, SetPrivacyPolicyRet)?)
+  }
+  pub async fn set_provenance_config(&self, arg0: ProvenanceConfig) -> Result<
+    SetProvenanceConfigRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_provenance_config", args).await?;
+    Ok(Decode!(&bytes, SetProvenanceConfigRet)?)
+  }
+  pub async fn set_reply_rules(&self, arg0: Option<Vec<ReplyRule>>) -> Result<
+    SetReplyRulesRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_reply_rules", args).await?;
+    Ok(Decode!(&bytes, SetReplyRulesRet)?)
+  }
+  pub async fn set_role(&self, arg0: Principal, arg1: Option<Role>) -> Result<
+    SetRoleRet
+  > {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("set_role", args).await?;
+    Ok(Decode!(&bytes, SetRoleRet)?)
+  }
+  pub async fn set_rollout_percent(&self, arg0: u64, arg1: u8) -> Result<
+    SetRolloutPercentRet
+  > {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("set_rollout_percent", args).await?;
+    Ok(Decode!(&bytes, SetRolloutPercentRet)?)
+  }
+  pub async fn set_sentiment_gate_config(
+    &self,
+    arg0: SentimentGateConfig,
+  ) -> Result<SetSentimentGateConfigRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_sentiment_gate_config", args).await?;
+    Ok(Decode!(&bytes, SetSentimentGateConfigRet)?)
+  }
+  pub async fn set_spending_limit(&self, arg0: SpendingLimit) -> Result<
+    SetSpendingLimitRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_spending_limit", args).await?;
+    Ok(Decode!(&bytes, SetSpendingLimitRet)?)
+  }
+  pub async fn set_sponsored_operation(
+    &self,
+    arg0: SponsoredOperation,
+  ) -> Result<SetSponsoredOperationRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_sponsored_operation", args).await?;
+    Ok(Decode!(&bytes, SetSponsoredOperationRet)?)
+  }
+  pub async fn set_sponsorship_policy(&self, arg0: SponsorshipPolicy) -> Result<
+    SetSponsorshipPolicyRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_sponsorship_policy", args).await?;
+    Ok(Decode!(&bytes, SetSponsorshipPolicyRet)?)
+  }
+  pub async fn set_storage_residency(
+    &self,
+    arg0: StorageSubsystem,
+    arg1: StorageResidency,
+  ) -> Result<SetStorageResidencyRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("set_storage_residency", args).await?;
+    Ok(Decode!(&bytes, SetStorageResidencyRet)?)
+  }
+  pub async fn set_transaction_tags(&self, arg0: Vec<String>) -> Result<
+    SetTransactionTagsRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_transaction_tags", args).await?;
+    Ok(Decode!(&bytes, SetTransactionTagsRet)?)
+  }
+  pub async fn set_twitter_oauth_2_token_proxy(
+    &self,
+    arg0: Option<String>,
+  ) -> Result<SetTwitterOauth2TokenProxyRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_twitter_oauth2_token_proxy", args).await?;
+    Ok(Decode!(&bytes, SetTwitterOauth2TokenProxyRet)?)
+  }
+  pub async fn set_twitter_searches(&self, arg0: Vec<TwitterSearch>) -> Result<
+    SetTwitterSearchesRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_twitter_searches", args).await?;
+    Ok(Decode!(&bytes, SetTwitterSearchesRet)?)
+  }
+  pub async fn set_watchdog_config(&self, arg0: WatchdogConfig) -> Result<
+    SetWatchdogConfigRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("set_watchdog_config", args).await?;
+    Ok(Decode!(&bytes, SetWatchdogConfigRet)?)
+  }
+  pub async fn set_workspace_character(
+    &self,
+    arg0: String,
+    arg1: Character,
+  ) -> Result<SetWorkspaceCharacterRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("set_workspace_character", args).await?;
+    Ok(Decode!(&bytes, SetWorkspaceCharacterRet)?)
+  }
+  pub async fn set_workspace_member(
+    &self,
+    arg0: String,
+    arg1: Principal,
+    arg2: Option<Role>,
+  ) -> Result<SetWorkspaceMemberRet> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.update("set_workspace_member", args).await?;
+    Ok(Decode!(&bytes, SetWorkspaceMemberRet)?)
+  }
+  pub async fn set_workspace_plan(
+    &self,
+    arg0: String,
+    arg1: Option<WorkspacePlan>,
+  ) -> Result<SetWorkspacePlanRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("set_workspace_plan", args).await?;
+    Ok(Decode!(&bytes, SetWorkspacePlanRet)?)
+  }
+  pub async fn set_workspace_policy(
+    &self,
+    arg0: String,
+    arg1: WorkspacePolicy,
+  ) -> Result<SetWorkspacePolicyRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("set_workspace_policy", args).await?;
+    Ok(Decode!(&bytes, SetWorkspacePolicyRet)?)
+  }
+  pub async fn sponsor_operation(&self, arg0: SponsoredCallRequest) -> Result<
+    SponsorOperationRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("sponsor_operation", args).await?;
+    Ok(Decode!(&bytes, SponsorOperationRet)?)
+  }
+  pub async fn start_auto_posting(
+    &self,
+    arg0: u64,
+    arg1: Vec<String>,
+    arg2: Option<String>,
+  ) -> Result<StartAutoPostingRet> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.update("start_auto_posting", args).await?;
+    Ok(Decode!(&bytes, StartAutoPostingRet)?)
+  }
+  pub async fn start_bridge_monitor(&self, arg0: u64) -> Result<
+    StartBridgeMonitorRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("start_bridge_monitor", args).await?;
+    Ok(Decode!(&bytes, StartBridgeMonitorRet)?)
+  }
+  pub async fn start_chain_maintenance(
+    &self,
+    arg0: ChainRef,
+    arg1: String,
+  ) -> Result<StartChainMaintenanceRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("start_chain_maintenance", args).await?;
+    Ok(Decode!(&bytes, StartChainMaintenanceRet)?)
+  }
+  pub async fn start_character_rollout(
+    &self,
+    arg0: String,
+    arg1: Character,
+    arg2: u8,
+  ) -> Result<StartCharacterRolloutRet> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.update("start_character_rollout", args).await?;
+    Ok(Decode!(&bytes, StartCharacterRolloutRet)?)
+  }
+  pub async fn start_deposit_monitor(&self, arg0: u64) -> Result<
+    StartDepositMonitorRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("start_deposit_monitor", args).await?;
+    Ok(Decode!(&bytes, StartDepositMonitorRet)?)
+  }
+  pub async fn start_faq_distillation(&self, arg0: u64) -> Result<
+    StartFaqDistillationRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("start_faq_distillation", args).await?;
+    Ok(Decode!(&bytes, StartFaqDistillationRet)?)
+  }
+  pub async fn start_social_polling(&self, arg0: u64) -> Result<
+    StartSocialPollingRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("start_social_polling", args).await?;
+    Ok(Decode!(&bytes, StartSocialPollingRet)?)
+  }
+  pub async fn start_twitter_oauth_2(
+    &self,
+    arg0: String,
+    arg1: Option<serde_bytes::ByteBuf>,
+    arg2: String,
+  ) -> Result<StartTwitterOauth2Ret> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.update("start_twitter_oauth2", args).await?;
+    Ok(Decode!(&bytes, StartTwitterOauth2Ret)?)
+  }
+  pub async fn state_version(&self) -> Result<u32> {
+    let args = Encode!()?;
+    let bytes = self.0.query("state_version", args).await?;
+    Ok(Decode!(&bytes, u32)?)
+  }
+  pub async fn stop_auto_posting(&self) -> Result<StopAutoPostingRet> {
+    let args = Encode!()?;
+    let bytes = self.0.update("stop_auto_posting", args).await?;
+    Ok(Decode!(&bytes, StopAutoPostingRet)?)
+  }
+  pub async fn stop_bridge_monitor(&self) -> Result<StopBridgeMonitorRet> {
+    let args = Encode!()?;
+    let bytes = self.0.update("stop_bridge_monitor", args).await?;
+    Ok(Decode!(&bytes, StopBridgeMonitorRet)?)
+  }
+  pub async fn stop_deposit_monitor(&self) -> Result<StopDepositMonitorRet> {
+    let args = Encode!()?;
+    let bytes = self.0.update("stop_deposit_monitor", args).await?;
+    Ok(Decode!(&bytes, StopDepositMonitorRet)?)
+  }
+  pub async fn stop_faq_distillation(&self) -> Result<StopFaqDistillationRet> {
+    let args = Encode!()?;
+    let bytes = self.0.update("stop_faq_distillation", args).await?;
+    Ok(Decode!(&bytes, StopFaqDistillationRet)?)
+  }
+  pub async fn stop_social_polling(&self) -> Result<StopSocialPollingRet> {
+    let args = Encode!()?;
+    let bytes = self.0.update("stop_social_polling", args).await?;
+    Ok(Decode!(&bytes, StopSocialPollingRet)?)
+  }
+  pub async fn store_anthropic_api_key(
+    &self,
+    arg0: serde_bytes::ByteBuf,
+  ) -> Result<StoreAnthropicApiKeyRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("store_anthropic_api_key", args).await?;
+    Ok(Decode!(&bytes, StoreAnthropicApiKeyRet)?)
+  }
+  pub async fn store_cms_webhook_secret(
+    &self,
+    arg0: serde_bytes::ByteBuf,
+  ) -> Result<StoreCmsWebhookSecretRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("store_cms_webhook_secret", args).await?;
+    Ok(Decode!(&bytes, StoreCmsWebhookSecretRet)?)
+  }
+  pub async fn store_encrypted_api_key(
+    &self,
+    arg0: serde_bytes::ByteBuf,
+  ) -> Result<StoreEncryptedApiKeyRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("store_encrypted_api_key", args).await?;
+    Ok(Decode!(&bytes, StoreEncryptedApiKeyRet)?)
+  }
+  pub async fn store_gemini_api_key(
+    &self,
+    arg0: serde_bytes::ByteBuf,
+  ) -> Result<StoreGeminiApiKeyRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("store_gemini_api_key", args).await?;
+    Ok(Decode!(&bytes, StoreGeminiApiKeyRet)?)
+  }
+  pub async fn submit_chat(&self, arg0: String) -> Result<SubmitChatRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("submit_chat", args).await?;
+    Ok(Decode!(&bytes, SubmitChatRet)?)
+  }
+  pub async fn subscribe_events(
+    &self,
+    arg0: String,
+    arg1: EventFilter,
+  ) -> Result<SubscribeEventsRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("subscribe_events", args).await?;
+    Ok(Decode!(&bytes, SubscribeEventsRet)?)
+  }
+  pub async fn sweep_unexpected_deposit(
+    &self,
+    arg0: u64,
+    arg1: String,
+  ) -> Result<SweepUnexpectedDepositRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("sweep_unexpected_deposit", args).await?;
+    Ok(Decode!(&bytes, SweepUnexpectedDepositRet)?)
+  }
+  pub async fn test_reply_rules(&self, arg0: ReplyRuleProbe) -> Result<
+    TestReplyRulesRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("test_reply_rules", args).await?;
+    Ok(Decode!(&bytes, TestReplyRulesRet)?)
+  }
+  pub async fn top_up_cycles(&self, arg0: u64) -> Result<TopUpCyclesRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("top_up_cycles", args).await?;
+    Ok(Decode!(&bytes, TopUpCyclesRet)?)
+  }
+  pub async fn transform_embedding_response(
+    &self,
+    arg0: TransformEmbeddingResponseArg,
+  ) -> Result<TransformEmbeddingResponseRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("transform_embedding_response", args).await?;
+    Ok(Decode!(&bytes, TransformEmbeddingResponseRet)?)
+  }
+  pub async fn transform_evm_response(
+    &self,
+    arg0: TransformEvmResponseArg,
+  ) -> Result<TransformEvmResponseRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("transform_evm_response", args).await?;
+    Ok(Decode!(&bytes, TransformEvmResponseRet)?)
+  }
+  pub async fn transform_farcaster_response(
+    &self,
+    arg0: TransformFarcasterResponseArg,
+  ) -> Result<TransformFarcasterResponseRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("transform_farcaster_response", args).await?;
+    Ok(Decode!(&bytes, TransformFarcasterResponseRet)?)
+  }
+  pub async fn transform_llm_response(
+    &self,
+    arg0: TransformLlmResponseArg,
+  ) -> Result<TransformLlmResponseRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("transform_llm_response", args).await?;
+    Ok(Decode!(&bytes, TransformLlmResponseRet)?)
+  }
+  pub async fn transform_mastodon_response(
+    &self,
+    arg0: TransformMastodonResponseArg,
+  ) -> Result<TransformMastodonResponseRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("transform_mastodon_response", args).await?;
+    Ok(Decode!(&bytes, TransformMastodonResponseRet)?)
+  }
+  pub async fn transform_openai_response(
+    &self,
+    arg0: TransformOpenaiResponseArg,
+  ) -> Result<TransformOpenaiResponseRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("transform_openai_response", args).await?;
+    Ok(Decode!(&bytes, TransformOpenaiResponseRet)?)
+  }
+  pub async fn transform_reddit_response(
+    &self,
+    arg0: TransformRedditResponseArg,
+  ) -> Result<TransformRedditResponseRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("transform_reddit_response", args).await?;
+    Ok(Decode!(&bytes, TransformRedditResponseRet)?)
+  }
+  pub async fn transform_social_response(
+    &self,
+    arg0: TransformSocialResponseArg,
+  ) -> Result<TransformSocialResponseRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("transform_social_response", args).await?;
+    Ok(Decode!(&bytes, TransformSocialResponseRet)?)
+  }
+  pub async fn transform_solana_response(
+    &self,
+    arg0: TransformSolanaResponseArg,
+  ) -> Result<TransformSolanaResponseRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("transform_solana_response", args).await?;
+    Ok(Decode!(&bytes, TransformSolanaResponseRet)?)
+  }
+  pub async fn transform_twitter_oauth_2_response(
+    &self,
+    arg0: TransformTwitterOauth2ResponseArg,
+  ) -> Result<TransformTwitterOauth2ResponseRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("transform_twitter_oauth2_response", args).await?;
+    Ok(Decode!(&bytes, TransformTwitterOauth2ResponseRet)?)
+  }
+  pub async fn trigger_auto_post(&self) -> Result<TriggerAutoPostRet> {
+    let args = Encode!()?;
+    let bytes = self.0.update("trigger_auto_post", args).await?;
+    Ok(Decode!(&bytes, TriggerAutoPostRet)?)
+  }
+  pub async fn trigger_faq_distillation(&self) -> Result<
+    TriggerFaqDistillationRet
+  > {
+    let args = Encode!()?;
+    let bytes = self.0.update("trigger_faq_distillation", args).await?;
+    Ok(Decode!(&bytes, TriggerFaqDistillationRet)?)
+  }
+  pub async fn trigger_poll(&self) -> Result<TriggerPollRet> {
+    let args = Encode!()?;
+    let bytes = self.0.update("trigger_poll", args).await?;
+    Ok(Decode!(&bytes, TriggerPollRet)?)
+  }
+  pub async fn unban_principal(&self, arg0: Principal) -> Result<
+    UnbanPrincipalRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("unban_principal", args).await?;
+    Ok(Decode!(&bytes, UnbanPrincipalRet)?)
+  }
+  pub async fn unblock_social_user(
+    &self,
+    arg0: SocialPlatform,
+    arg1: String,
+  ) -> Result<UnblockSocialUserRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("unblock_social_user", args).await?;
+    Ok(Decode!(&bytes, UnblockSocialUserRet)?)
+  }
+  pub async fn unsubscribe_events(&self, arg0: u64) -> Result<
+    UnsubscribeEventsRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("unsubscribe_events", args).await?;
+    Ok(Decode!(&bytes, UnsubscribeEventsRet)?)
+  }
+  pub async fn update_character(&self, arg0: Character) -> Result<
+    UpdateCharacterRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("update_character", args).await?;
+    Ok(Decode!(&bytes, UpdateCharacterRet)?)
+  }
+  pub async fn update_ckbtc_balance(&self) -> Result<UpdateCkbtcBalanceRet> {
+    let args = Encode!()?;
+    let bytes = self.0.update("update_ckbtc_balance", args).await?;
+    Ok(Decode!(&bytes, UpdateCkbtcBalanceRet)?)
+  }
+  pub async fn upload_twitter_media(
+    &self,
+    arg0: serde_bytes::ByteBuf,
+    arg1: String,
+  ) -> Result<UploadTwitterMediaRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("upload_twitter_media", args).await?;
+    Ok(Decode!(&bytes, UploadTwitterMediaRet)?)
+  }
+  pub async fn validate_governance_action(
+    &self,
+    arg0: GovernanceAction,
+  ) -> Result<ValidateGovernanceActionRet> {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("validate_governance_action", args).await?;
+    Ok(Decode!(&bytes, ValidateGovernanceActionRet)?)
+  }
+  pub async fn verify_address_book_entry(
+    &self,
+    arg0: String,
+    arg1: ChainRef,
+    arg2: bool,
+  ) -> Result<VerifyAddressBookEntryRet> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.update("verify_address_book_entry", args).await?;
+    Ok(Decode!(&bytes, VerifyAddressBookEntryRet)?)
+  }
+  pub async fn verify_discord_interaction(
+    &self,
+    arg0: String,
+    arg1: String,
+    arg2: serde_bytes::ByteBuf,
+  ) -> Result<VerifyDiscordInteractionRet> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.query("verify_discord_interaction", args).await?;
+    Ok(Decode!(&bytes, VerifyDiscordInteractionRet)?)
+  }
+  pub async fn verify_provenance(&self, arg0: String) -> Result<
+    Option<ProvenanceRecord>
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.query("verify_provenance", args).await?;
+    Ok(Decode!(&bytes, Option<ProvenanceRecord>)?)
+  }
+  pub async fn version(&self) -> Result<String> {
+    let args = Encode!()?;
+    let bytes = self.0.query("version", args).await?;
+    Ok(Decode!(&bytes, String)?)
+  }
+  pub async fn void_workspace_invoice(&self, arg0: u64) -> Result<
+    VoidWorkspaceInvoiceRet
+  > {
+    let args = Encode!(&arg0)?;
+    let bytes = self.0.update("void_workspace_invoice", args).await?;
+    Ok(Decode!(&bytes, VoidWorkspaceInvoiceRet)?)
+  }
+  pub async fn withdraw_ckerc_20(
+    &self,
+    arg0: Principal,
+    arg1: candid::Nat,
+    arg2: Option<String>,
+  ) -> Result<WithdrawCkerc20Ret> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.update("withdraw_ckerc20", args).await?;
+    Ok(Decode!(&bytes, WithdrawCkerc20Ret)?)
+  }
+  pub async fn withdraw_cketh(
+    &self,
+    arg0: candid::Nat,
+    arg1: Option<String>,
+  ) -> Result<WithdrawCkethRet> {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("withdraw_cketh", args).await?;
+    Ok(Decode!(&bytes, WithdrawCkethRet)?)
+  }
+  pub async fn workspace_chat(&self, arg0: String, arg1: String) -> Result<
+    WorkspaceChatRet
+  > {
+    let args = Encode!(&arg0, &arg1)?;
+    let bytes = self.0.update("workspace_chat", args).await?;
+    Ok(Decode!(&bytes, WorkspaceChatRet)?)
+  }
+  pub async fn workspace_post(
+    &self,
+    arg0: String,
+    arg1: SocialPlatform,
+    arg2: String,
+  ) -> Result<WorkspacePostRet> {
+    let args = Encode!(&arg0, &arg1, &arg2)?;
+    let bytes = self.0.update("workspace_post", args).await?;
+    Ok(Decode!(&bytes, WorkspacePostRet)?)
+  }
+}
@@ -0,0 +1,43 @@
+//! Typed client for the Coo agent canister.
+//!
+//! `generated.rs` holds a Rust type for every Candid type and one method per endpoint on
+//! [`CooClient`]. It is regenerated from `eliza_backend.did` with `cargo xtask codegen`.
+//! The client doesn't pick an agent: implement [`Transport`] over ic-agent, PocketIC or an
+//! inter-canister call, and wrap it in a `CooClient`.
+
+#[allow(clippy::all, non_camel_case_types, non_snake_case)]
+mod generated;
+
+pub use generated::*;
+use std::future::Future;
+
+#[derive(Debug)]
+pub enum Error {
+    /// Arguments or the reply didn't match the interface
+    Candid(candid::Error),
+    /// The call itself failed (rejected, timed out, ...)
+    Transport(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Candid(e) => write!(f, "Candid error: {}", e),
+            Error::Transport(e) => write!(f, "Call failed: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<candid::Error> for Error {
+    fn from(e: candid::Error) -> Self {
+        Error::Candid(e)
+    }
+}
+
+/// Sends Candid-encoded arguments to the canister and returns the encoded reply
+pub trait Transport {
+    fn query(&self, method: &str, args: Vec<u8>) -> impl Future<Output = Result<Vec<u8>, Error>>;
+    fn update(&self, method: &str, args: Vec<u8>) -> impl Future<Output = Result<Vec<u8>, Error>>;
+}
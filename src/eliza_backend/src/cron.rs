@@ -0,0 +1,261 @@
+//! Cron expressions for post and task schedules, evaluated in UTC against IC time.
+//!
+//! The usual five fields: minute, hour, day of month, month and day of week. Each field takes
+//! `*`, numbers, ranges (`1-5`), lists (`1,15`) and steps (`*/15`, `9-17/2`). Months and
+//! weekdays can also be given by their three-letter English names, and Sunday is 0 or 7.
+//! `@hourly`, `@daily`, `@weekly` and `@monthly` are accepted as shorthands. As in Vixie cron,
+//! a day matches if either day field matches when both are restricted; a day field starting
+//! with `*` (`*`, `*/2`) counts as unrestricted, and the two are then ANDed.
+//! "Every weekday at 14:00 UTC" is `0 14 * * MON-FRI`.
+
+const NS_PER_MINUTE: u64 = 60_000_000_000;
+const MINUTES_PER_DAY: u64 = 1_440;
+/// Long enough for any schedule that fires at all, such as 29 February on a Monday
+const SEARCH_DAYS: u64 = 366 * 28;
+
+const MONTH_NAMES: [&str; 12] = ["JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC"];
+const WEEKDAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// A parsed expression; each field is a bitmask of the values it allows
+#[derive(Clone, Debug, PartialEq)]
+pub struct Schedule {
+    minutes: u64,
+    hours: u32,
+    days: u32,         // Bits 1-31
+    months: u16,       // Bits 1-12
+    weekdays: u8,      // Bits 0-6, Sunday first
+    any_day: bool,     // Day-of-month field starts with `*`
+    any_weekday: bool, // Day-of-week field starts with `*`
+}
+
+impl Schedule {
+    pub fn parse(expr: &str) -> Result<Schedule, String> {
+        let expr = expr.trim();
+        let expanded = match expr.to_ascii_lowercase().as_str() {
+            "@hourly" => "0 * * * *",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            _ => expr,
+        };
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(format!("Cron expression needs 5 fields, got {}: {}", fields.len(), expr));
+        };
+
+        // Day 7 is Sunday again
+        let weekdays = parse_field(weekday, 0, 7, &WEEKDAY_NAMES, "day of week")?;
+        let schedule = Schedule {
+            minutes: parse_field(minute, 0, 59, &[], "minute")?,
+            hours: parse_field(hour, 0, 23, &[], "hour")? as u32,
+            days: parse_field(day, 1, 31, &[], "day of month")? as u32,
+            months: parse_field(month, 1, 12, &MONTH_NAMES, "month")? as u16,
+            weekdays: ((weekdays | (weekdays >> 7)) & 0x7f) as u8,
+            any_day: day.starts_with('*'),
+            any_weekday: weekday.starts_with('*'),
+        };
+        if schedule.next_after(0).is_none() {
+            return Err(format!("Cron expression never fires: {}", expr));
+        }
+        Ok(schedule)
+    }
+
+    /// The first minute strictly after `after_ns` that the schedule fires, in ns since the epoch
+    pub fn next_after(&self, after_ns: u64) -> Option<u64> {
+        let start = after_ns / NS_PER_MINUTE + 1;
+        let first_day = start / MINUTES_PER_DAY;
+        for day in first_day..first_day + SEARCH_DAYS {
+            if !self.matches_day(day) {
+                continue;
+            }
+            let from = if day == first_day { start % MINUTES_PER_DAY } else { 0 };
+            let minute_of_day = (from..MINUTES_PER_DAY).find(|m| {
+                self.hours & (1 << (m / 60)) != 0 && self.minutes & (1 << (m % 60)) != 0
+            });
+            if let Some(m) = minute_of_day {
+                return Some((day * MINUTES_PER_DAY + m) * NS_PER_MINUTE);
+            }
+        }
+        None
+    }
+
+    /// The shortest gap between consecutive firings in the `horizon_ns` after `from_ns`.
+    /// Stops early once a gap below `floor_ns` turns up.
+    pub fn min_gap(&self, from_ns: u64, horizon_ns: u64, floor_ns: u64) -> Option<u64> {
+        let mut previous = self.next_after(from_ns)?;
+        let mut gap: Option<u64> = None;
+        while let Some(next) = self.next_after(previous) {
+            if next > from_ns.saturating_add(horizon_ns) {
+                break;
+            }
+            let this_gap = next - previous;
+            gap = Some(gap.map_or(this_gap, |g| g.min(this_gap)));
+            if this_gap < floor_ns {
+                break;
+            }
+            previous = next;
+        }
+        gap
+    }
+
+    fn matches_day(&self, day: u64) -> bool {
+        let (_, month, day_of_month) = civil_from_days(day);
+        if self.months & (1 << month) == 0 {
+            return false;
+        }
+        // 1970-01-01 was a Thursday
+        let weekday_match = self.weekdays & (1 << ((day + 4) % 7)) != 0;
+        let day_match = self.days & (1 << day_of_month) != 0;
+        if self.any_day || self.any_weekday {
+            day_match && weekday_match
+        } else {
+            day_match || weekday_match
+        }
+    }
+}
+
+/// One field as a bitmask over `min..=max`
+fn parse_field(field: &str, min: u64, max: u64, names: &[&str], label: &str) -> Result<u64, String> {
+    let invalid = || format!("Invalid cron {} field: {}", label, field);
+    let value = |text: &str| -> Result<u64, String> {
+        let upper = text.to_ascii_uppercase();
+        let named = names.iter().position(|n| *n == upper).map(|i| i as u64 + min);
+        let n = match named {
+            Some(n) => n,
+            None => text.parse::<u64>().map_err(|_| invalid())?,
+        };
+        if n < min || n > max {
+            return Err(format!("Cron {} {} is outside {}-{}", label, n, min, max));
+        }
+        Ok(n)
+    };
+
+    let mut mask = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            // A step past the whole range could only ever hit its start
+            Some((range, step)) => (range, step.parse::<u64>().ok().filter(|s| *s > 0 && *s <= max - min).ok_or_else(invalid)?),
+            None => (part, 1),
+        };
+        let (low, high) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((low, high)) => (value(low)?, value(high)?),
+                // `5/15` runs from 5 to the end of the range
+                None if step > 1 => (value(range)?, max),
+                None => {
+                    let n = value(range)?;
+                    (n, n)
+                }
+            },
+        };
+        if low > high {
+            return Err(invalid());
+        }
+        for n in (low..=high).step_by(step as usize) {
+            mask |= 1 << n;
+        }
+    }
+    Ok(mask)
+}
+
+/// The UTC date of a time in ns since the epoch, as YYYY-MM-DD
+pub fn format_date(ns: u64) -> String {
+    let (year, month, day) = civil_from_days(ns / NS_PER_MINUTE / MINUTES_PER_DAY);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Year, month (1-12) and day (1-31) of a day count since 1970-01-01
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    // Howard Hinnant's algorithm, shifted so years start in March
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ns since the epoch of a UTC date and time
+    fn at(year: u64, month: u64, day: u64, hour: u64, minute: u64) -> u64 {
+        // Inverse of civil_from_days
+        let year = if month <= 2 { year - 1 } else { year };
+        let era = year / 400;
+        let yoe = year - era * 400;
+        let mp = if month > 2 { month - 3 } else { month + 9 };
+        let doy = (153 * mp + 2) / 5 + day - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        let days = era * 146_097 + doe - 719_468;
+        (days * MINUTES_PER_DAY + hour * 60 + minute) * NS_PER_MINUTE
+    }
+
+    fn next(expr: &str, after: u64) -> u64 {
+        Schedule::parse(expr).unwrap().next_after(after).unwrap()
+    }
+
+    #[test]
+    fn parse_accepts_names_shorthands_and_sunday_as_seven() {
+        assert_eq!(Schedule::parse("@daily").unwrap(), Schedule::parse("0 0 * * *").unwrap());
+        assert_eq!(Schedule::parse("0 0 * JAN SUN").unwrap(), Schedule::parse("0 0 * 1 0").unwrap());
+        assert_eq!(Schedule::parse("0 0 * * 7").unwrap(), Schedule::parse("0 0 * * 0").unwrap());
+        assert_eq!(Schedule::parse("0 9-17/4 * * *").unwrap(), Schedule::parse("0 9,13,17 * * *").unwrap());
+    }
+
+    #[test]
+    fn parse_rejects_bad_expressions() {
+        assert!(Schedule::parse("* * *").is_err());
+        assert!(Schedule::parse("60 * * * *").is_err());
+        assert!(Schedule::parse("0 17-9 * * *").is_err());
+        assert!(Schedule::parse("*/0 * * * *").is_err());
+        assert!(Schedule::parse("*/60 * * * *").is_err());
+        // Would truncate to a zero step on a 32-bit usize
+        assert!(Schedule::parse("*/4294967296 * * * *").is_err());
+        assert!(Schedule::parse("0 0 31 2 *").is_err());
+    }
+
+    #[test]
+    fn restricted_day_fields_match_either() {
+        // 2024-05-28 is a Tuesday: the 1st (a Saturday) comes before the next Monday
+        assert_eq!(next("0 0 1 * MON", at(2024, 5, 28, 0, 0)), at(2024, 6, 1, 0, 0));
+    }
+
+    #[test]
+    fn a_starred_day_field_is_unrestricted() {
+        // Odd days that are also Mondays: 1 June 2024 is a Saturday, 3 June a Monday
+        assert_eq!(next("0 0 */2 * MON", at(2024, 5, 28, 0, 0)), at(2024, 6, 3, 0, 0));
+        assert_eq!(next("0 14 * * MON-FRI", at(2024, 1, 1, 0, 0)), at(2024, 1, 1, 14, 0));
+    }
+
+    #[test]
+    fn next_after_crosses_months_and_leap_years() {
+        assert_eq!(next("0 0 1 * *", at(2024, 1, 31, 12, 0)), at(2024, 2, 1, 0, 0));
+        assert_eq!(next("0 0 29 2 *", at(2023, 3, 1, 0, 0)), at(2024, 2, 29, 0, 0));
+        // 2100 isn't a leap year
+        assert_eq!(next("0 0 29 2 *", at(2096, 3, 1, 0, 0)), at(2104, 2, 29, 0, 0));
+        // Strictly after: a firing at exactly `after` is skipped
+        assert_eq!(next("30 * * * *", at(2024, 1, 1, 10, 30)), at(2024, 1, 1, 11, 30));
+        assert_eq!(format_date(at(2024, 2, 29, 23, 59)), "2024-02-29");
+    }
+
+    #[test]
+    fn min_gap_finds_the_tightest_spacing() {
+        let day = MINUTES_PER_DAY * NS_PER_MINUTE;
+        let every_15 = Schedule::parse("*/15 * * * *").unwrap();
+        assert_eq!(every_15.min_gap(at(2024, 1, 1, 0, 0), day, 0), Some(15 * NS_PER_MINUTE));
+
+        let twice_daily = Schedule::parse("0 9,17 * * *").unwrap();
+        assert_eq!(twice_daily.min_gap(at(2024, 1, 1, 0, 0), 2 * day, 0), Some(8 * 60 * NS_PER_MINUTE));
+
+        // Nothing twice inside the horizon
+        let yearly = Schedule::parse("0 0 1 1 *").unwrap();
+        assert_eq!(yearly.min_gap(at(2024, 6, 1, 0, 0), day, 0), None);
+    }
+}
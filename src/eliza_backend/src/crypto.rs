@@ -0,0 +1,169 @@
+//! Ed25519 signing and verification primitives.
+//!
+//! Signing uses the subnet's threshold Schnorr (Ed25519) key, so no private key
+//! ever lives in canister memory. Verification is local via ed25519-dalek.
+
+use candid::Principal;
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use ic_cdk::api::management_canister::schnorr::{
+    schnorr_public_key, sign_with_schnorr, SchnorrAlgorithm, SchnorrKeyId,
+    SchnorrPublicKeyArgument, SignWithSchnorrArgument,
+};
+use std::cell::RefCell;
+
+/// Derivation path for signing outbound webhook payloads
+pub const WEBHOOK_SIGNING_PATH: &[u8] = b"webhook-signing";
+/// Derivation path for signing exported persona bundles
+pub const PERSONA_SIGNING_PATH: &[u8] = b"persona-signing";
+
+/// What ic-cdk attaches to sign_with_schnorr; the unused part is refunded
+const SIGN_WITH_SCHNORR_FEE: u128 = 26_153_846_153;
+
+thread_local! {
+    // Signing fees paid since the canister last collected them
+    static SPENT: RefCell<u128> = const { RefCell::new(0) };
+}
+
+/// Signing fees paid since the last call, resetting the count
+pub fn take_spent_cycles() -> u128 {
+    SPENT.with(|s| std::mem::take(&mut *s.borrow_mut()))
+}
+
+/// Threshold Schnorr key used for Ed25519 signatures
+fn schnorr_key_id() -> SchnorrKeyId {
+    // Use "key_1" for mainnet, "dfx_test_key" for local
+    SchnorrKeyId {
+        algorithm: SchnorrAlgorithm::Ed25519,
+        name: "key_1".to_string(),
+    }
+}
+
+/// Ed25519 public key (32 bytes) for the given derivation path. `canister` defaults to this
+/// canister; passing another canister's ID derives *its* key, which lets us check provenance
+/// without trusting anything that canister tells us.
+pub async fn ed25519_public_key(canister: Option<Principal>, path: &[u8]) -> Result<Vec<u8>, String> {
+    let request = SchnorrPublicKeyArgument {
+        canister_id: canister,
+        derivation_path: vec![path.to_vec()],
+        key_id: schnorr_key_id(),
+    };
+
+    let (response,) = schnorr_public_key(request)
+        .await
+        .map_err(|(code, msg)| format!("Schnorr public key error: {:?} - {}", code, msg))?;
+
+    Ok(response.public_key)
+}
+
+/// Sign `message` with the Ed25519 key for the given derivation path
+pub async fn sign_ed25519(path: &[u8], message: &[u8]) -> Result<Vec<u8>, String> {
+    let request = SignWithSchnorrArgument {
+        message: message.to_vec(),
+        derivation_path: vec![path.to_vec()],
+        key_id: schnorr_key_id(),
+    };
+
+    let result = sign_with_schnorr(request).await;
+    let charged = SIGN_WITH_SCHNORR_FEE.saturating_sub(ic_cdk::api::call::msg_cycles_refunded128());
+    SPENT.with(|s| *s.borrow_mut() += charged);
+    let (response,) = result.map_err(|(code, msg)| format!("Schnorr signing error: {:?} - {}", code, msg))?;
+
+    Ok(response.signature)
+}
+
+/// Verify an Ed25519 signature against a raw 32-byte public key
+pub fn verify_ed25519(public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<(), String> {
+    let key_bytes: [u8; 32] = public_key
+        .try_into()
+        .map_err(|_| "Public key must be 32 bytes".to_string())?;
+    let sig_bytes: [u8; 64] = signature
+        .try_into()
+        .map_err(|_| "Signature must be 64 bytes".to_string())?;
+
+    let verifying_key = VerifyingKey::from_bytes(&key_bytes)
+        .map_err(|e| format!("Invalid public key: {}", e))?;
+
+    verifying_key
+        .verify(message, &Signature::from_bytes(&sig_bytes))
+        .map_err(|_| "Invalid signature".to_string())
+}
+
+/// Message layout shared by Discord interactions and our outbound webhooks: timestamp || body
+pub fn signed_message(timestamp: &str, body: &[u8]) -> Vec<u8> {
+    let mut message = Vec::with_capacity(timestamp.len() + body.len());
+    message.extend_from_slice(timestamp.as_bytes());
+    message.extend_from_slice(body);
+    message
+}
+
+/// Verify a Discord interaction request (`X-Signature-Ed25519` / `X-Signature-Timestamp` headers)
+pub fn verify_discord_interaction(
+    public_key_hex: &str,
+    signature_hex: &str,
+    timestamp: &str,
+    body: &[u8],
+) -> Result<(), String> {
+    let public_key = hex::decode(public_key_hex)
+        .map_err(|e| format!("Invalid public key hex: {}", e))?;
+    let signature = hex::decode(signature_hex)
+        .map_err(|e| format!("Invalid signature hex: {}", e))?;
+
+    verify_ed25519(&public_key, &signed_message(timestamp, body), &signature)
+}
+
+/// Sign an outbound webhook payload; returns the hex signature
+pub async fn sign_webhook_payload(timestamp: &str, body: &[u8]) -> Result<String, String> {
+    let signature = sign_ed25519(WEBHOOK_SIGNING_PATH, &signed_message(timestamp, body)).await?;
+    Ok(hex::encode(signature))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    // RFC 8032 test vector 1
+    const SECRET_KEY: &str = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+    const PUBLIC_KEY: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+    const TIMESTAMP: &str = "1700000000";
+    const BODY: &[u8] = br#"{"type":1}"#;
+
+    fn sign(timestamp: &str, body: &[u8]) -> String {
+        let secret: [u8; 32] = hex::decode(SECRET_KEY).unwrap().try_into().unwrap();
+        hex::encode(SigningKey::from_bytes(&secret).sign(&signed_message(timestamp, body)).to_bytes())
+    }
+
+    #[test]
+    fn rfc_8032_vector_verifies() {
+        let signature = hex::decode(
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+        )
+        .unwrap();
+        assert_eq!(verify_ed25519(&hex::decode(PUBLIC_KEY).unwrap(), b"", &signature), Ok(()));
+    }
+
+    #[test]
+    fn accepts_a_signed_interaction() {
+        assert_eq!(verify_discord_interaction(PUBLIC_KEY, &sign(TIMESTAMP, BODY), TIMESTAMP, BODY), Ok(()));
+    }
+
+    #[test]
+    fn rejects_tampered_interactions() {
+        let signature = sign(TIMESTAMP, BODY);
+        let invalid = Err("Invalid signature".to_string());
+        assert_eq!(verify_discord_interaction(PUBLIC_KEY, &signature, TIMESTAMP, br#"{"type":2}"#), invalid);
+        assert_eq!(verify_discord_interaction(PUBLIC_KEY, &signature, "1700000001", BODY), invalid);
+
+        let mut flipped = hex::decode(&signature).unwrap();
+        flipped[0] ^= 1;
+        assert_eq!(verify_discord_interaction(PUBLIC_KEY, &hex::encode(flipped), TIMESTAMP, BODY), invalid);
+    }
+
+    #[test]
+    fn rejects_malformed_keys_and_signatures() {
+        let signature = sign(TIMESTAMP, BODY);
+        assert!(verify_discord_interaction("not hex", &signature, TIMESTAMP, BODY).is_err());
+        assert!(verify_discord_interaction(&PUBLIC_KEY[..62], &signature, TIMESTAMP, BODY).is_err());
+        assert!(verify_discord_interaction(PUBLIC_KEY, &signature[..126], TIMESTAMP, BODY).is_err());
+    }
+}
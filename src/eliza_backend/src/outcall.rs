@@ -0,0 +1,307 @@
+//! Shared HTTPS outcall client.
+//!
+//! Every outcall goes through `send` or `send_idempotent`, which validate the target host, fail
+//! fast while a host's circuit breaker is open or cycles are too low for outcalls, and retry
+//! transient failures with jittered backoff. Reads are retried on 429, 5xx and transient
+//! rejects; writes only on 429, where the host turned the request away without acting on it.
+//! Retries stop at the attempt limit or when the call's cycles budget would be exceeded. Both
+//! keep the signature of `http_request`, so call sites handle errors as before.
+
+use candid::{CandidType, Deserialize};
+use ic_cdk::api::call::{CallResult, RejectionCode};
+use ic_cdk::api::management_canister::http_request::{
+    http_request, CanisterHttpRequestArgument, HttpMethod, HttpResponse,
+};
+use serde::Serialize;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::net::Ipv4Addr;
+
+const NS_PER_SEC: u64 = 1_000_000_000;
+
+/// Admin-tunable client settings (persisted by the canister)
+#[derive(CandidType, Deserialize, Serialize, Clone, Debug)]
+pub struct OutcallPolicy {
+    pub allowed_hosts: Option<Vec<String>>,   // None = any valid host; ".example.com" also admits subdomains
+    pub max_get_attempts: u8,                 // Attempts per call, including the first
+    pub breaker_threshold: u32,               // Consecutive failures that open a host's breaker
+    pub breaker_cooldown_seconds: u64,        // How long an open breaker rejects calls
+    pub retry_budget_cycles: Option<u128>,    // Most one call may spend across its attempts; None = no cap
+}
+
+impl Default for OutcallPolicy {
+    fn default() -> Self {
+        OutcallPolicy {
+            allowed_hosts: None,
+            max_get_attempts: 3,
+            breaker_threshold: 5,
+            breaker_cooldown_seconds: 60,
+            retry_budget_cycles: None,
+        }
+    }
+}
+
+/// Breaker state for one host, as reported to admins
+#[derive(CandidType, Deserialize, Clone, Debug, Default)]
+pub struct HostHealth {
+    pub host: String,
+    pub consecutive_failures: u32,
+    pub open_until: Option<u64>,
+    pub last_failure: Option<String>,
+    pub requests: u64,
+    pub failures: u64,
+}
+
+thread_local! {
+    static POLICY: RefCell<OutcallPolicy> = RefCell::new(OutcallPolicy::default());
+    // Heap only: breakers start closed after an upgrade
+    static HOSTS: RefCell<HashMap<String, HostHealth>> = RefCell::new(HashMap::new());
+    // Set by the canister while cycles are too low for outcalls
+    static BLOCKED: RefCell<Option<String>> = const { RefCell::new(None) };
+    // Cycles charged per host since the canister last collected them
+    static SPENT: RefCell<HashMap<String, u128>> = RefCell::new(HashMap::new());
+}
+
+pub fn policy() -> OutcallPolicy {
+    POLICY.with(|p| p.borrow().clone())
+}
+
+pub fn set_policy(policy: OutcallPolicy) -> Result<(), String> {
+    if policy.max_get_attempts == 0 || policy.max_get_attempts > 5 {
+        return Err("max_get_attempts must be between 1 and 5".to_string());
+    }
+    if policy.retry_budget_cycles == Some(0) {
+        return Err("retry_budget_cycles must be positive; use null for no cap".to_string());
+    }
+    if policy.breaker_threshold == 0 {
+        return Err("breaker_threshold must be at least 1".to_string());
+    }
+    for host in policy.allowed_hosts.iter().flatten() {
+        let bare = host.strip_prefix('.').unwrap_or(host);
+        validate_host(bare).map_err(|e| format!("Allowed host '{}': {}", host, e))?;
+    }
+    POLICY.with(|p| *p.borrow_mut() = policy);
+    Ok(())
+}
+
+/// Refuse every outcall with `reason` until called again with None
+pub fn set_blocked(reason: Option<String>) {
+    BLOCKED.with(|b| *b.borrow_mut() = reason);
+}
+
+/// Cycles charged per host since the last call, resetting the counts
+pub fn take_spent_cycles() -> HashMap<String, u128> {
+    SPENT.with(|s| std::mem::take(&mut *s.borrow_mut()))
+}
+
+pub fn host_health() -> Vec<HostHealth> {
+    let mut hosts: Vec<HostHealth> = HOSTS.with(|h| h.borrow().values().cloned().collect());
+    hosts.sort_by(|a, b| a.host.cmp(&b.host));
+    hosts
+}
+
+/// Close a host's breaker by hand
+pub fn reset_host(host: &str) -> bool {
+    HOSTS.with(|h| {
+        h.borrow_mut().get_mut(host).map(|health| {
+            health.consecutive_failures = 0;
+            health.open_until = None;
+        }).is_some()
+    })
+}
+
+/// Lower-cased host of an https URL; rejects credentials, other schemes and malformed hosts
+pub fn url_host(url: &str) -> Result<String, String> {
+    let rest = url.strip_prefix("https://").ok_or("Outcalls must use https")?;
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    if authority.contains('@') {
+        return Err("Credentials in URLs are not allowed".to_string());
+    }
+    if authority.starts_with('[') {
+        return Err("IP address hosts are not allowed".to_string());
+    }
+    let host = match authority.rsplit_once(':') {
+        Some((host, port)) if port.chars().all(|c| c.is_ascii_digit()) && !port.is_empty() => host,
+        _ => authority,
+    };
+    let host = host.to_ascii_lowercase();
+    validate_host(&host)?;
+    Ok(host)
+}
+
+/// Outcalls resolve names over IPv6 from the subnet, so only public DNS names make sense
+fn validate_host(host: &str) -> Result<(), String> {
+    if host.is_empty() || host.len() > 253 {
+        return Err("Invalid host".to_string());
+    }
+    if host.parse::<Ipv4Addr>().is_ok() || host.contains(':') {
+        return Err("IP address hosts are not allowed".to_string());
+    }
+    if !host.contains('.') || host.ends_with(".local") || host.ends_with(".internal") || host.ends_with(".localhost") {
+        return Err(format!("'{}' is not a public host name", host));
+    }
+    let valid_label = |label: &str| {
+        !label.is_empty()
+            && label.len() <= 63
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    };
+    if !host.split('.').all(valid_label) {
+        return Err(format!("'{}' is not a valid host name", host));
+    }
+    Ok(())
+}
+
+fn host_allowed(host: &str, allowed: &Option<Vec<String>>) -> bool {
+    match allowed {
+        None => true,
+        Some(hosts) => hosts.iter().any(|entry| match entry.strip_prefix('.') {
+            Some(domain) => host == domain || host.ends_with(&format!(".{}", domain)),
+            None => host == entry,
+        }),
+    }
+}
+
+fn reject(msg: String) -> (RejectionCode, String) {
+    (RejectionCode::CanisterReject, msg)
+}
+
+/// Count a request against the host; an open breaker rejects it. Once the cooldown has passed
+/// the breaker lets requests through again, and the first result decides whether it re-opens.
+fn admit(host: &str, now: u64) -> Result<(), String> {
+    HOSTS.with(|h| {
+        let mut hosts = h.borrow_mut();
+        let health = hosts.entry(host.to_string()).or_insert_with(|| HostHealth {
+            host: host.to_string(),
+            ..Default::default()
+        });
+        if let Some(until) = health.open_until {
+            if now < until {
+                return Err(format!(
+                    "Circuit open for {} ({}s left): {}",
+                    host,
+                    (until - now).div_ceil(NS_PER_SEC),
+                    health.last_failure.as_deref().unwrap_or("repeated failures")
+                ));
+            }
+        }
+        health.requests += 1;
+        Ok(())
+    })
+}
+
+fn record_result(host: &str, failure: Option<String>, now: u64) {
+    let policy = policy();
+    HOSTS.with(|h| {
+        if let Some(health) = h.borrow_mut().get_mut(host) {
+            match failure {
+                None => {
+                    health.consecutive_failures = 0;
+                    health.open_until = None;
+                }
+                Some(reason) => {
+                    health.failures += 1;
+                    health.consecutive_failures += 1;
+                    health.last_failure = Some(reason);
+                    if health.consecutive_failures >= policy.breaker_threshold {
+                        health.open_until = Some(now + policy.breaker_cooldown_seconds * NS_PER_SEC);
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Failures that say something about the host rather than about our request
+fn failure_reason(result: &CallResult<(HttpResponse,)>) -> Option<String> {
+    match result {
+        Ok((response,)) => {
+            let status = &response.status;
+            if *status == 429u32 || *status >= 500u32 {
+                Some(format!("HTTP {}", status))
+            } else {
+                None
+            }
+        }
+        Err((RejectionCode::SysTransient, msg)) | Err((RejectionCode::SysFatal, msg)) => Some(msg.clone()),
+        Err(_) => None,
+    }
+}
+
+/// Wait one to `2^attempt` consensus rounds, picked from raw_rand. Awaiting the management
+/// canister keeps us in the caller's call context, which a timer-based sleep would not.
+async fn backoff(attempt: u32) {
+    let rounds = match ic_cdk::api::management_canister::main::raw_rand().await {
+        Ok((bytes,)) => 1 + bytes.first().copied().unwrap_or(0) as u32 % (1 << attempt),
+        Err(_) => 1,
+    };
+    for _ in 1..rounds {
+        let _ = ic_cdk::api::management_canister::main::raw_rand().await;
+    }
+}
+
+/// Whether a request may be repeated after a failure
+#[derive(Clone, Copy, PartialEq)]
+enum Retry {
+    /// Reads, including JSON-RPC reads and LLM completions sent as POST
+    Idempotent,
+    /// Writes: only a 429 says for sure the host didn't act on the request
+    RateLimited,
+}
+
+fn retryable(result: &CallResult<(HttpResponse,)>, retry: Retry) -> bool {
+    match retry {
+        Retry::Idempotent => failure_reason(result).is_some(),
+        Retry::RateLimited => matches!(result, Ok((response,)) if response.status == 429u32),
+    }
+}
+
+/// Drop-in replacement for `http_request`. GET and HEAD are treated as reads, anything else
+/// as a write.
+pub async fn send(request: CanisterHttpRequestArgument, cycles: u128) -> CallResult<(HttpResponse,)> {
+    let retry = if matches!(request.method, HttpMethod::GET | HttpMethod::HEAD) {
+        Retry::Idempotent
+    } else {
+        Retry::RateLimited
+    };
+    send_with(request, cycles, retry).await
+}
+
+/// `send` for a POST that has no side effects and is safe to repeat
+pub async fn send_idempotent(request: CanisterHttpRequestArgument, cycles: u128) -> CallResult<(HttpResponse,)> {
+    send_with(request, cycles, Retry::Idempotent).await
+}
+
+async fn send_with(request: CanisterHttpRequestArgument, cycles: u128, retry: Retry) -> CallResult<(HttpResponse,)> {
+    if let Some(reason) = BLOCKED.with(|b| b.borrow().clone()) {
+        return Err(reject(reason));
+    }
+    let policy = policy();
+    let host = url_host(&request.url).map_err(reject)?;
+    if !host_allowed(&host, &policy.allowed_hosts) {
+        return Err(reject(format!("Host not in outcall allowlist: {}", host)));
+    }
+
+    let attempts = policy.max_get_attempts.max(1) as u32;
+    let budget = policy.retry_budget_cycles.unwrap_or(u128::MAX);
+
+    let mut attempt = 0;
+    let mut spent = 0u128;
+    loop {
+        attempt += 1;
+        admit(&host, ic_cdk::api::time()).map_err(reject)?;
+
+        let result = http_request(request.clone(), cycles).await;
+        let charged = cycles.saturating_sub(ic_cdk::api::call::msg_cycles_refunded128());
+        spent = spent.saturating_add(charged);
+        SPENT.with(|s| *s.borrow_mut().entry(host.clone()).or_default() += charged);
+        let again = retryable(&result, retry) && attempt < attempts && spent.saturating_add(cycles) <= budget;
+        record_result(&host, failure_reason(&result), ic_cdk::api::time());
+
+        if !again {
+            return result;
+        }
+        backoff(attempt).await;
+    }
+}
@@ -0,0 +1,397 @@
+//! Secrets at rest, sealed with a vetKD-derived key.
+//!
+//! The canister asks the vetKD system API for a key bound to its own ID. The key arrives
+//! encrypted under a throwaway transport key and is decrypted here, then hashed into the
+//! AES-256-GCM key that seals API keys, social credentials and the Solana secret. Only
+//! ciphertext is persisted. The AES key lives on the heap and is derived again after an
+//! upgrade; secrets are opened only for the call that needs them.
+//!
+//! Keys come in epochs, each derived from its own vetKD input. Sealed values record the epoch
+//! they were sealed under. New values use the current epoch, and once everything has been
+//! resealed, older epochs are retired and refused.
+//!
+//! Clients can also have vetKeys of their own under other contexts, delivered encrypted to
+//! their transport key. Those are for end-to-end encryption; the canister never holds them.
+
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use bls12_381::{pairing, G1Affine, G1Projective, G2Affine, Scalar};
+use candid::{CandidType, Deserialize, Principal};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256, Sha512};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Marks sealed values; plaintext credentials never start with a NUL byte.
+/// `vk1` values are epoch 0; `vk2` values carry their epoch as 4 big-endian bytes.
+const SEALED_PREFIX: &[u8] = b"\0vk1";
+const SEALED_PREFIX_EPOCH: &[u8] = b"\0vk2";
+const NONCE_LEN: usize = 12;
+
+/// vetKD inputs: one key per canister and epoch, used only for secrets at rest
+const DERIVATION_CONTEXT: &[u8] = b"coo-icp/secrets-at-rest";
+const DERIVATION_INPUT: &[u8] = b"v1";
+/// Cycles attached to vetkd_derive_key; the management canister refunds what it doesn't charge
+const VETKD_DERIVE_CYCLES: u128 = 26_153_846_153;
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+enum VetKdCurve {
+    #[serde(rename = "bls12_381_g2")]
+    Bls12381G2,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct VetKdKeyId {
+    curve: VetKdCurve,
+    name: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct VetKdDeriveKeyArgs {
+    input: Vec<u8>,
+    context: Vec<u8>,
+    transport_public_key: Vec<u8>,
+    key_id: VetKdKeyId,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct VetKdDeriveKeyResult {
+    encrypted_key: Vec<u8>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct VetKdPublicKeyArgs {
+    canister_id: Option<Principal>,
+    context: Vec<u8>,
+    key_id: VetKdKeyId,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct VetKdPublicKeyResult {
+    public_key: Vec<u8>,
+}
+
+/// Which key epochs exist, persisted by the canister
+#[derive(CandidType, Deserialize, serde::Serialize, Clone, Debug, Default)]
+pub struct EpochState {
+    pub current: u32,
+    pub checks: Vec<(u32, Vec<u8>)>,   // Fingerprint of each epoch's key, recorded on first derivation
+    pub retired: Vec<u32>,
+}
+
+thread_local! {
+    // Heap only: derived again after an upgrade
+    static KEYS: RefCell<HashMap<u32, [u8; 32]>> = RefCell::new(HashMap::new());
+    static NONCE_COUNTER: RefCell<u32> = const { RefCell::new(0) };
+    static EPOCHS: RefCell<EpochState> = RefCell::new(EpochState::default());
+    // Derivation fees paid since the canister last collected them
+    static SPENT: RefCell<u128> = const { RefCell::new(0) };
+}
+
+/// Derivation fees paid since the last call, resetting the count
+pub fn take_spent_cycles() -> u128 {
+    SPENT.with(|s| std::mem::take(&mut *s.borrow_mut()))
+}
+
+fn vetkd_key_id() -> VetKdKeyId {
+    // Use "key_1" for mainnet, "dfx_test_key" for local
+    VetKdKeyId {
+        curve: VetKdCurve::Bls12381G2,
+        name: "key_1".to_string(),
+    }
+}
+
+/// Fingerprint of the epoch 0 key, kept in its original place in the canister state
+pub fn key_check() -> Option<Vec<u8>> {
+    epoch_check(0)
+}
+
+pub fn set_key_check(check: Option<Vec<u8>>) {
+    EPOCHS.with(|e| {
+        let mut epochs = e.borrow_mut();
+        epochs.checks.retain(|(epoch, _)| *epoch != 0);
+        if let Some(check) = check {
+            epochs.checks.push((0, check));
+        }
+    });
+}
+
+fn epoch_check(epoch: u32) -> Option<Vec<u8>> {
+    EPOCHS.with(|e| e.borrow().checks.iter().find(|(n, _)| *n == epoch).map(|(_, c)| c.clone()))
+}
+
+pub fn epoch_state() -> EpochState {
+    EPOCHS.with(|e| e.borrow().clone())
+}
+
+/// Restore the epochs after an upgrade. Snapshots from before epochs only carry the
+/// epoch 0 fingerprint, restored through `set_key_check`.
+pub fn set_epoch_state(state: EpochState) {
+    EPOCHS.with(|e| *e.borrow_mut() = state);
+}
+
+pub fn current_epoch() -> u32 {
+    EPOCHS.with(|e| e.borrow().current)
+}
+
+pub fn key_loaded() -> bool {
+    let current = current_epoch();
+    KEYS.with(|k| k.borrow().contains_key(&current))
+}
+
+pub fn is_sealed(bytes: &[u8]) -> bool {
+    sealed_epoch(bytes).is_some()
+}
+
+/// The epoch a sealed value was sealed under
+pub fn sealed_epoch(bytes: &[u8]) -> Option<u32> {
+    if bytes.starts_with(SEALED_PREFIX) {
+        return Some(0);
+    }
+    let epoch = bytes.strip_prefix(SEALED_PREFIX_EPOCH)?.get(..4)?;
+    Some(u32::from_be_bytes(epoch.try_into().expect("4-byte slice")))
+}
+
+fn derivation_input(epoch: u32) -> Vec<u8> {
+    match epoch {
+        0 => DERIVATION_INPUT.to_vec(),
+        n => format!("epoch-{}", n).into_bytes(),
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; 32] {
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(data);
+    mac.finalize().into_bytes().into()
+}
+
+/// Single-block HKDF-SHA256 (RFC 5869) with an empty salt
+fn hkdf_sha256(ikm: &[u8], info: &[u8]) -> [u8; 32] {
+    let prk = hmac_sha256(&[0u8; 32], ikm);
+    let mut block = info.to_vec();
+    block.push(1);
+    hmac_sha256(&prk, &block)
+}
+
+/// Call vetkd_derive_key and count its fee
+async fn vetkd_derive_key(args: VetKdDeriveKeyArgs) -> Result<Vec<u8>, String> {
+    let result = ic_cdk::api::call::call_with_payment128(
+        Principal::management_canister(),
+        "vetkd_derive_key",
+        (args,),
+        VETKD_DERIVE_CYCLES,
+    )
+    .await;
+    let charged = VETKD_DERIVE_CYCLES.saturating_sub(ic_cdk::api::call::msg_cycles_refunded128());
+    SPENT.with(|s| *s.borrow_mut() += charged);
+    let (result,): (VetKdDeriveKeyResult,) = result.map_err(|(code, msg)| format!("vetKD derive key error: {:?} - {}", code, msg))?;
+    Ok(result.encrypted_key)
+}
+
+/// A vetKey for a client, encrypted under the client's transport key. The canister only
+/// passes it on and can't decrypt it, so what the client encrypts with it stays unreadable
+/// here. Contexts must differ from the one used for secrets at rest.
+pub async fn encrypted_client_key(context: &[u8], input: &[u8], transport_public_key: &[u8]) -> Result<Vec<u8>, String> {
+    if context == DERIVATION_CONTEXT {
+        return Err("The secrets-at-rest context is not available to clients".to_string());
+    }
+    let raw: [u8; 48] = transport_public_key
+        .try_into()
+        .map_err(|_| "Transport public key must be a 48-byte compressed G1 point".to_string())?;
+    if bool::from(G1Affine::from_compressed(&raw).is_none()) {
+        return Err("Transport public key is not a valid G1 point".to_string());
+    }
+    vetkd_derive_key(VetKdDeriveKeyArgs {
+        input: input.to_vec(),
+        context: context.to_vec(),
+        transport_public_key: raw.to_vec(),
+        key_id: vetkd_key_id(),
+    })
+    .await
+}
+
+/// The canister's derived public key for a context, which clients check their vetKeys against
+pub async fn client_verification_key(context: &[u8]) -> Result<Vec<u8>, String> {
+    let (result,): (VetKdPublicKeyResult,) = ic_cdk::call(
+        Principal::management_canister(),
+        "vetkd_public_key",
+        (VetKdPublicKeyArgs { canister_id: None, context: context.to_vec(), key_id: vetkd_key_id() },),
+    )
+    .await
+    .map_err(|(code, msg)| format!("vetKD public key error: {:?} - {}", code, msg))?;
+    Ok(result.public_key)
+}
+
+/// Fetch the vetKey and decrypt it with a fresh transport key. Layout of the encrypted key:
+/// c1 (G1, 48 bytes) || c2 (G2, 96 bytes) || c3 (G1, 48 bytes); the vetKey is c3 - tsk * c1.
+async fn derive_vetkey(epoch: u32) -> Result<[u8; 48], String> {
+    let (seed,) = ic_cdk::api::management_canister::main::raw_rand()
+        .await
+        .map_err(|(code, msg)| format!("Failed to get random bytes: {:?} - {}", code, msg))?;
+    let wide: [u8; 64] = Sha512::digest(&seed).into();
+    let transport_secret = Scalar::from_bytes_wide(&wide);
+    let transport_public = G1Affine::from(G1Projective::generator() * transport_secret);
+
+    let bytes = vetkd_derive_key(VetKdDeriveKeyArgs {
+        input: derivation_input(epoch),
+        context: DERIVATION_CONTEXT.to_vec(),
+        transport_public_key: transport_public.to_compressed().to_vec(),
+        key_id: vetkd_key_id(),
+    })
+    .await?;
+    if bytes.len() != 192 {
+        return Err(format!("Encrypted vetKey must be 192 bytes, got {}", bytes.len()));
+    }
+    let g1 = |range: std::ops::Range<usize>| -> Result<G1Affine, String> {
+        let raw: [u8; 48] = bytes[range].try_into().expect("48-byte slice");
+        Option::from(G1Affine::from_compressed(&raw)).ok_or_else(|| "Invalid G1 point in encrypted vetKey".to_string())
+    };
+    let c1 = g1(0..48)?;
+    let c3 = g1(144..192)?;
+    let c2_raw: [u8; 96] = bytes[48..144].try_into().expect("96-byte slice");
+    let c2: G2Affine = Option::from(G2Affine::from_compressed(&c2_raw))
+        .ok_or_else(|| "Invalid G2 point in encrypted vetKey".to_string())?;
+
+    // c1 and c2 must share the same ephemeral exponent
+    if pairing(&c1, &G2Affine::generator()) != pairing(&G1Affine::generator(), &c2) {
+        return Err("Encrypted vetKey failed its consistency check".to_string());
+    }
+
+    let vetkey = G1Affine::from(G1Projective::from(c3) - c1 * transport_secret);
+    Ok(vetkey.to_compressed())
+}
+
+/// Derive the current epoch's sealing key unless it is already on the heap
+pub async fn ensure_key() -> Result<(), String> {
+    ensure_epoch_key(current_epoch()).await
+}
+
+/// Derive an epoch's key unless it is already on the heap. The first derivation records a
+/// fingerprint; later ones must match it, or secrets sealed earlier would be unreadable.
+pub async fn ensure_epoch_key(epoch: u32) -> Result<(), String> {
+    if EPOCHS.with(|e| e.borrow().retired.contains(&epoch)) {
+        return Err(format!("Secrets key epoch {} is retired", epoch));
+    }
+    if KEYS.with(|k| k.borrow().contains_key(&epoch)) {
+        return Ok(());
+    }
+
+    let vetkey = derive_vetkey(epoch).await?;
+    let key = hkdf_sha256(&vetkey, b"coo-icp/secrets-at-rest/aes-256-gcm");
+    let check = hmac_sha256(&key, b"key-check")[..16].to_vec();
+
+    match epoch_check(epoch) {
+        Some(expected) if expected != check => {
+            return Err("Derived secrets key doesn't match the key secrets were sealed with".to_string());
+        }
+        Some(_) => {}
+        None => EPOCHS.with(|e| e.borrow_mut().checks.push((epoch, check))),
+    }
+    KEYS.with(|k| k.borrow_mut().insert(epoch, key));
+    Ok(())
+}
+
+/// Derive the next epoch's key and seal new values with it. Values sealed earlier stay
+/// readable until their epoch is retired.
+pub async fn start_epoch() -> Result<u32, String> {
+    let next = EPOCHS.with(|e| {
+        let epochs = e.borrow();
+        epochs.checks.iter().map(|(n, _)| *n).chain([epochs.current]).chain(epochs.retired.iter().copied()).max().unwrap_or(0) + 1
+    });
+    ensure_epoch_key(next).await?;
+    EPOCHS.with(|e| e.borrow_mut().current = next);
+    Ok(next)
+}
+
+/// Forget an epoch's key and refuse values sealed under it from now on
+pub fn retire_epoch(epoch: u32) -> Result<(), String> {
+    if epoch == current_epoch() {
+        return Err("The current epoch can't be retired".to_string());
+    }
+    KEYS.with(|k| k.borrow_mut().remove(&epoch));
+    EPOCHS.with(|e| {
+        let mut epochs = e.borrow_mut();
+        if !epochs.retired.contains(&epoch) {
+            epochs.retired.push(epoch);
+        }
+    });
+    Ok(())
+}
+
+fn cipher(epoch: u32) -> Result<Aes256Gcm, String> {
+    let key = KEYS.with(|k| k.borrow().get(&epoch).copied())
+        .ok_or("Secrets key not loaded yet; try again shortly")?;
+    Ok(Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key)))
+}
+
+/// Nonces are time || counter: time moves on every round and the counter within one
+fn next_nonce() -> [u8; NONCE_LEN] {
+    let counter = NONCE_COUNTER.with(|c| {
+        let mut c = c.borrow_mut();
+        *c = c.wrapping_add(1);
+        *c
+    });
+    let mut nonce = [0u8; NONCE_LEN];
+    nonce[..8].copy_from_slice(&ic_cdk::api::time().to_be_bytes());
+    nonce[8..].copy_from_slice(&counter.to_be_bytes());
+    nonce
+}
+
+/// Seal with the current epoch's loaded key: prefix [|| epoch] || nonce || AES-256-GCM ciphertext
+pub fn seal(plaintext: &[u8]) -> Result<Vec<u8>, String> {
+    let epoch = current_epoch();
+    let nonce = next_nonce();
+    let ciphertext = cipher(epoch)?
+        .encrypt(Nonce::from_slice(&nonce), plaintext)
+        .map_err(|_| "Sealing failed".to_string())?;
+
+    let mut sealed = Vec::with_capacity(SEALED_PREFIX_EPOCH.len() + 4 + NONCE_LEN + ciphertext.len());
+    if epoch == 0 {
+        sealed.extend_from_slice(SEALED_PREFIX);
+    } else {
+        sealed.extend_from_slice(SEALED_PREFIX_EPOCH);
+        sealed.extend_from_slice(&epoch.to_be_bytes());
+    }
+    sealed.extend_from_slice(&nonce);
+    sealed.extend_from_slice(&ciphertext);
+    Ok(sealed)
+}
+
+/// Open a value sealed by `seal` with its epoch's loaded key
+pub fn open(sealed: &[u8]) -> Result<Vec<u8>, String> {
+    let epoch = sealed_epoch(sealed).ok_or("Secret is not sealed")?;
+    if EPOCHS.with(|e| e.borrow().retired.contains(&epoch)) {
+        return Err(format!("Secret was sealed under retired epoch {}", epoch));
+    }
+    let header = if epoch == 0 && sealed.starts_with(SEALED_PREFIX) { SEALED_PREFIX.len() } else { SEALED_PREFIX_EPOCH.len() + 4 };
+    let body = &sealed[header..];
+    if body.len() < NONCE_LEN {
+        return Err("Sealed secret is truncated".to_string());
+    }
+    let (nonce, ciphertext) = body.split_at(NONCE_LEN);
+    cipher(epoch)?
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "Sealed secret failed authentication".to_string())
+}
+
+pub async fn seal_secret(plaintext: &[u8]) -> Result<Vec<u8>, String> {
+    ensure_key().await?;
+    seal(plaintext)
+}
+
+pub async fn open_secret(sealed: &[u8]) -> Result<Vec<u8>, String> {
+    if let Some(epoch) = sealed_epoch(sealed) {
+        ensure_epoch_key(epoch).await?;
+    }
+    open(sealed)
+}
+
+/// Seal again under the current epoch; plaintext values are sealed for the first time.
+/// Returns None when the value is already current.
+pub fn reseal(stored: &[u8]) -> Result<Option<Vec<u8>>, String> {
+    match sealed_epoch(stored) {
+        Some(epoch) if epoch == current_epoch() => Ok(None),
+        Some(_) => seal(&open(stored)?).map(Some),
+        None => seal(stored).map(Some),
+    }
+}
@@ -0,0 +1,91 @@
+//! Repository tasks, run with `cargo xtask <task>`.
+//!
+//! `codegen` regenerates the typed client in `src/coo_client` from `eliza_backend.did`.
+//! The output is checked in, so the client crate builds (and publishes) on its own; a change
+//! to the interface shows up as a diff to `coo_client/src/generated.rs` in the same commit.
+//! `codegen --check` fails instead of writing when the checked-in file is out of date.
+
+use candid_parser::bindings::rust::{compile, Config, Target};
+use candid_parser::typing::pretty_check_file;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+const DID: &str = "src/eliza_backend/eliza_backend.did";
+const GENERATED: &str = "src/coo_client/src/generated.rs";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        ["codegen"] => codegen(false),
+        ["codegen", "--check"] => codegen(true),
+        _ => {
+            eprintln!("usage: cargo xtask codegen [--check]");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().expect("xtask lives in the workspace").to_path_buf()
+}
+
+fn codegen(check: bool) -> ExitCode {
+    let root = workspace_root();
+    let generated = root.join(GENERATED);
+    let source = client_source(&root.join(DID));
+    let current = std::fs::read_to_string(&generated).unwrap_or_default();
+    if current == source {
+        return ExitCode::SUCCESS;
+    }
+    if check {
+        eprintln!("{} is out of date; run `cargo xtask codegen` and commit the result", GENERATED);
+        return ExitCode::FAILURE;
+    }
+    std::fs::write(&generated, source).expect("failed to write coo_client/src/generated.rs");
+    println!("wrote {}", GENERATED);
+    ExitCode::SUCCESS
+}
+
+fn client_source(did: &Path) -> String {
+    let (env, actor) = pretty_check_file(did).expect("eliza_backend.did must type-check");
+
+    let mut config = Config::new();
+    config
+        .set_target(Target::Agent)
+        .set_service_name("coo_client".to_string())
+        .set_type_attributes("#[derive(CandidType, Deserialize, Clone, Debug)]".to_string());
+    let bindings = transport_bindings(&compile(&config, &env, &actor));
+
+    format!(
+        "// @generated from src/eliza_backend/eliza_backend.did by `cargo xtask codegen`. Do not edit.\n{}",
+        bindings
+    )
+}
+
+/// The agent target calls ic-agent directly; route its calls through the client's Transport
+/// instead, so callers can bring any agent (or an inter-canister call)
+fn transport_bindings(agent: &str) -> String {
+    let mut out = String::with_capacity(agent.len());
+    for line in agent.lines() {
+        let line = if line.starts_with("// ") {
+            continue;
+        } else if line.starts_with("type Result<T>") {
+            "type Result<T> = std::result::Result<T, crate::Error>;".to_string()
+        } else if line.starts_with("pub struct CooClient<'a>") {
+            "pub struct CooClient<T>(pub T);".to_string()
+        } else if line.starts_with("impl<'a> CooClient<'a>") {
+            line.replacen("impl<'a> CooClient<'a>", "impl<T: crate::Transport> CooClient<T>", 1)
+        } else if let Some(call) = line.trim_start().strip_prefix("let bytes = self.1.") {
+            // `query(&self.0, "name").with_arg(args).call().await?;` and the update equivalent
+            let (kind, rest) = call.split_once("(&self.0, ").expect("agent call shape");
+            let (method, _) = rest.split_once(')').expect("agent call shape");
+            let indent = &line[..line.len() - line.trim_start().len()];
+            format!("{}let bytes = self.0.{}({}, args).await?;", indent, kind, method)
+        } else {
+            line.to_string()
+        };
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out
+}